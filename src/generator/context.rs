@@ -0,0 +1,281 @@
+// Copyright 2013 The gl-rs developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// This module is written into the generated bindings as `gl::context`, so it
+// may only depend on `std`.
+
+static VENDOR: u32                  = 0x1F00;
+static RENDERER: u32                = 0x1F01;
+static VERSION: u32                 = 0x1F02;
+static SHADING_LANGUAGE_VERSION: u32 = 0x8B8C;
+static MAJOR_VERSION: u32           = 0x821B;
+static MINOR_VERSION: u32           = 0x821C;
+static CONTEXT_FLAGS: u32           = 0x821E;
+static CONTEXT_PROFILE_MASK: u32    = 0x9126;
+
+static CONTEXT_CORE_PROFILE_BIT: i32            = 0x1;
+static CONTEXT_COMPATIBILITY_PROFILE_BIT: i32   = 0x2;
+static CONTEXT_FLAG_FORWARD_COMPATIBLE_BIT: i32 = 0x1;
+static CONTEXT_FLAG_DEBUG_BIT: i32              = 0x2;
+static CONTEXT_FLAG_ROBUST_ACCESS_BIT: i32      = 0x4;
+
+/// The API implemented by a context
+#[deriving(Clone, Eq)]
+pub enum Api {
+    /// Desktop OpenGL
+    DesktopApi,
+    /// OpenGL ES, including the ES-CM and ES-CL profiles of ES 1.x
+    EmbeddedApi,
+}
+
+/// The profile of a desktop context
+#[deriving(Clone, Eq)]
+pub enum Profile {
+    CoreProfile,
+    /// Also used for contexts older than 3.2, which have no profiles
+    CompatibilityProfile,
+}
+
+/// A version parsed from a `VERSION` string
+#[deriving(Clone, Eq)]
+pub struct Version {
+    api: Api,
+    major: uint,
+    minor: uint,
+    release: Option<uint>,
+    /// The vendor-specific information following the version number
+    vendor_info: ~str,
+}
+
+impl Version {
+    /// Parses a `VERSION` string, such as `4.3.0 NVIDIA 331.38` or
+    /// `OpenGL ES 3.0 Apple A7 GPU`.
+    pub fn parse(s: &str) -> Option<Version> {
+        let s = s.trim();
+        let (api, rest) = match strip_prefixes(s, ["OpenGL ES-CM ", "OpenGL ES-CL ", "OpenGL ES "]) {
+            Some(rest) => (EmbeddedApi, rest),
+            None => (DesktopApi, s),
+        };
+        let (numbers, vendor_info) = split_number(rest);
+        let mut numbers = numbers.split_iter('.').map(|n| from_str::<uint>(n));
+        match (numbers.next(), numbers.next(), numbers.next()) {
+            (Some(Some(major)), Some(Some(minor)), release) => Some(Version {
+                api: api,
+                major: major,
+                minor: minor,
+                release: release.and_then(|r| r),
+                vendor_info: vendor_info.trim().to_owned(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the version is at least `major.minor`
+    pub fn is_at_least(&self, major: uint, minor: uint) -> bool {
+        self.major > major || (self.major == major && self.minor >= minor)
+    }
+}
+
+/// Parses a `SHADING_LANGUAGE_VERSION` string, such as `4.30 NVIDIA via Cg
+/// compiler` or `OpenGL ES GLSL ES 3.00`, returning the major and minor
+/// version.
+pub fn parse_glsl_version(s: &str) -> Option<(uint, uint)> {
+    let s = s.trim();
+    let rest = strip_prefixes(s, ["OpenGL ES GLSL ES "]).unwrap_or(s);
+    let (numbers, _) = split_number(rest);
+    let mut numbers = numbers.split_iter('.').map(|n| from_str::<uint>(n));
+    match (numbers.next(), numbers.next()) {
+        (Some(Some(major)), Some(Some(minor))) => Some((major, minor)),
+        _ => None,
+    }
+}
+
+fn strip_prefixes<'a>(s: &'a str, prefixes: &[&str]) -> Option<&'a str> {
+    prefixes.iter().find(|p| s.starts_with(**p)).map(|p| s.slice_from(p.len()))
+}
+
+/// Splits a string into the leading version number and the remainder
+fn split_number<'a>(s: &'a str) -> (&'a str, &'a str) {
+    match s.find(|c: char| !(c.is_digit() || c == '.')) {
+        Some(i) => (s.slice_to(i), s.slice_from(i)),
+        None => (s, ""),
+    }
+}
+
+/// The flags that a context was created with
+#[deriving(Clone, Eq)]
+pub struct ContextFlags {
+    debug: bool,
+    forward_compatible: bool,
+    robust_access: bool,
+}
+
+/// A description of the current context
+#[deriving(Clone, Eq)]
+pub struct ContextInfo {
+    version: Version,
+    /// The profile of a desktop context, or `None` for OpenGL ES
+    profile: Option<Profile>,
+    flags: ContextFlags,
+    vendor: ~str,
+    renderer: ~str,
+    /// The major and minor version of GLSL, if it could be parsed
+    glsl_version: Option<(uint, uint)>,
+}
+
+impl ContextInfo {
+    /// Builds the description of a context from the results of `GetString` and
+    /// `GetIntegerv`, querying only the values that the context's version
+    /// supports. Returns `None` if the version string cannot be parsed.
+    pub fn from_queries(get_string: &fn(u32) -> ~str, get_integer: &fn(u32) -> i32) -> Option<ContextInfo> {
+        let mut version = match Version::parse(get_string(VERSION)) {
+            Some(version) => version,
+            None => return None,
+        };
+        let is_desktop = version.api == DesktopApi;
+        if version.is_at_least(3, 0) {
+            // some drivers report the highest version they support in the string
+            version.major = get_integer(MAJOR_VERSION) as uint;
+            version.minor = get_integer(MINOR_VERSION) as uint;
+        }
+        let profile = if !is_desktop {
+            None
+        } else if version.is_at_least(3, 2) {
+            let mask = get_integer(CONTEXT_PROFILE_MASK);
+            if mask & CONTEXT_CORE_PROFILE_BIT != 0 && mask & CONTEXT_COMPATIBILITY_PROFILE_BIT == 0 {
+                Some(CoreProfile)
+            } else {
+                Some(CompatibilityProfile)
+            }
+        } else {
+            Some(CompatibilityProfile)
+        };
+        let flags = if (is_desktop && version.is_at_least(3, 0)) || version.is_at_least(3, 2) {
+            get_integer(CONTEXT_FLAGS)
+        } else {
+            0
+        };
+        Some(ContextInfo {
+            version: version,
+            profile: profile,
+            flags: ContextFlags {
+                debug: flags & CONTEXT_FLAG_DEBUG_BIT != 0,
+                forward_compatible: flags & CONTEXT_FLAG_FORWARD_COMPATIBLE_BIT != 0,
+                robust_access: flags & CONTEXT_FLAG_ROBUST_ACCESS_BIT != 0,
+            },
+            vendor: get_string(VENDOR),
+            renderer: get_string(RENDERER),
+            glsl_version: parse_glsl_version(get_string(SHADING_LANGUAGE_VERSION)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(s: &str) -> (Api, uint, uint, Option<uint>, ~str) {
+        let v = Version::parse(s).expect(s.to_owned());
+        (v.api, v.major, v.minor, v.release, v.vendor_info)
+    }
+
+    #[test]
+    fn test_desktop_versions() {
+        assert_eq!(version("4.3.0 NVIDIA 331.38"), (DesktopApi, 4, 3, Some(0), ~"NVIDIA 331.38"));
+        assert_eq!(version("3.3 (Core Profile) Mesa 10.1.0"), (DesktopApi, 3, 3, None, ~"(Core Profile) Mesa 10.1.0"));
+        assert_eq!(version("2.1 ATI-1.6.36"), (DesktopApi, 2, 1, None, ~"ATI-1.6.36"));
+        assert_eq!(version("4.1 INTEL-8.24.11"), (DesktopApi, 4, 1, None, ~"INTEL-8.24.11"));
+        assert_eq!(version("1.4 (2.1 Mesa 7.0.4)"), (DesktopApi, 1, 4, None, ~"(2.1 Mesa 7.0.4)"));
+        assert_eq!(version("4.4.0 - Build 20.19.15.4531"), (DesktopApi, 4, 4, Some(0), ~"- Build 20.19.15.4531"));
+        assert_eq!(version("3.0"), (DesktopApi, 3, 0, None, ~""));
+    }
+
+    #[test]
+    fn test_embedded_versions() {
+        assert_eq!(version("OpenGL ES 3.0 Apple A7 GPU - 50.6"), (EmbeddedApi, 3, 0, None, ~"Apple A7 GPU - 50.6"));
+        assert_eq!(version("OpenGL ES 2.0 (ANGLE 1.2.0.2450)"), (EmbeddedApi, 2, 0, None, ~"(ANGLE 1.2.0.2450)"));
+        assert_eq!(version("OpenGL ES-CM 1.1"), (EmbeddedApi, 1, 1, None, ~""));
+        assert_eq!(version("OpenGL ES-CL 1.0 build 12"), (EmbeddedApi, 1, 0, None, ~"build 12"));
+    }
+
+    #[test]
+    fn test_invalid_versions() {
+        assert_eq!(Version::parse(""), None);
+        assert_eq!(Version::parse("OpenGL ES"), None);
+        assert_eq!(Version::parse("unknown 4.3"), None);
+        assert_eq!(Version::parse("4"), None);
+    }
+
+    #[test]
+    fn test_glsl_versions() {
+        assert_eq!(parse_glsl_version("4.30 NVIDIA via Cg compiler"), Some((4, 30)));
+        assert_eq!(parse_glsl_version("OpenGL ES GLSL ES 3.00"), Some((3, 0)));
+        assert_eq!(parse_glsl_version("1.20"), Some((1, 20)));
+        assert_eq!(parse_glsl_version("4.40 - Build 20.19.15.4531"), Some((4, 40)));
+        assert_eq!(parse_glsl_version(""), None);
+    }
+
+    fn queries(version: &str, integers: &[(u32, i32)]) -> Option<ContextInfo> {
+        let get_string = |name: u32| match name {
+            VERSION => version.to_owned(),
+            VENDOR => ~"Vendor",
+            RENDERER => ~"Renderer",
+            SHADING_LANGUAGE_VERSION => ~"1.50",
+            _ => fail!("Unexpected GetString({})", name),
+        };
+        let get_integer = |name: u32| match integers.iter().find(|&&(n, _)| n == name) {
+            Some(&(_, value)) => value,
+            None => fail!("Unexpected GetIntegerv({})", name),
+        };
+        ContextInfo::from_queries(get_string, get_integer)
+    }
+
+    #[test]
+    fn test_core_context() {
+        let info = queries("4.3.0 NVIDIA 331.38", [(MAJOR_VERSION, 3), (MINOR_VERSION, 2),
+                                                   (CONTEXT_PROFILE_MASK, CONTEXT_CORE_PROFILE_BIT),
+                                                   (CONTEXT_FLAGS, 0x3)]).unwrap();
+        assert_eq!((info.version.major, info.version.minor), (3, 2));
+        assert_eq!(info.profile, Some(CoreProfile));
+        assert_eq!(info.flags, ContextFlags { debug: true, forward_compatible: true, robust_access: false });
+        assert_eq!(info.vendor, ~"Vendor");
+        assert_eq!(info.renderer, ~"Renderer");
+        assert_eq!(info.glsl_version, Some((1, 50)));
+    }
+
+    #[test]
+    fn test_legacy_context() {
+        // no integer queries are valid before 3.0
+        let info = queries("2.1 ATI-1.6.36", []).unwrap();
+        assert_eq!(info.profile, Some(CompatibilityProfile));
+        assert_eq!(info.flags, ContextFlags { debug: false, forward_compatible: false, robust_access: false });
+    }
+
+    #[test]
+    fn test_embedded_context() {
+        let info = queries("OpenGL ES 2.0 (ANGLE 1.2.0.2450)", []).unwrap();
+        assert_eq!(info.version.api, EmbeddedApi);
+        assert_eq!(info.profile, None);
+
+        let info = queries("OpenGL ES 3.2 V@258.0", [(MAJOR_VERSION, 3), (MINOR_VERSION, 2),
+                                                    (CONTEXT_FLAGS, 0x4)]).unwrap();
+        assert!(info.flags.robust_access);
+    }
+
+    #[test]
+    fn test_unparseable_version() {
+        assert!(queries("unknown", []).is_none());
+    }
+}
@@ -0,0 +1,715 @@
+// Copyright 2013 The gl-rs developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use ty::Src;
+
+/// The commands implemented by the emulation in `SRC`. The generated mock
+/// functions for these commands forward to the emulation after recording the
+/// call.
+pub static CMDS: &'static [&'static str] = &[
+    "GetError",
+    "GetIntegerv",
+    "GenBuffers",
+    "DeleteBuffers",
+    "IsBuffer",
+    "BindBuffer",
+    "BufferData",
+    "GenTextures",
+    "DeleteTextures",
+    "IsTexture",
+    "ActiveTexture",
+    "BindTexture",
+    "GenVertexArrays",
+    "DeleteVertexArrays",
+    "IsVertexArray",
+    "BindVertexArray",
+    "EnableVertexAttribArray",
+    "DisableVertexAttribArray",
+    "VertexAttribPointer",
+    "CreateShader",
+    "DeleteShader",
+    "IsShader",
+    "ShaderSource",
+    "CompileShader",
+    "GetShaderiv",
+    "CreateProgram",
+    "DeleteProgram",
+    "IsProgram",
+    "AttachShader",
+    "LinkProgram",
+    "UseProgram",
+    "GetProgramiv",
+    "GetAttribLocation",
+    "BindFragDataLocation",
+    "GenFramebuffers",
+    "DeleteFramebuffers",
+    "IsFramebuffer",
+    "BindFramebuffer",
+    "CheckFramebufferStatus",
+    "DrawArrays",
+];
+
+/// An emulation of the object model of OpenGL, tracking the lifetimes and
+/// bindings of buffers, textures, vertex arrays, shaders, programs and
+/// framebuffers, and raising the errors that the specification requires for
+/// invalid usage. This is written out as the `emulate` module.
+pub static SRC: Src = &[
+    "use std::hashmap::{HashMap, HashSet};",
+    "use std::libc::*;",
+    "use std::local_data;",
+    "use std::ptr;",
+    "use std::str;",
+    "use super::types::*;",
+    "",
+    "// Enum values are written out in full so that the emulation does not depend",
+    "// on which versions and extensions the bindings were generated for.",
+    "static NO_ERROR: GLenum = 0;",
+    "static INVALID_ENUM: GLenum = 0x0500;",
+    "static INVALID_VALUE: GLenum = 0x0501;",
+    "static INVALID_OPERATION: GLenum = 0x0502;",
+    "static INVALID_FRAMEBUFFER_OPERATION: GLenum = 0x0506;",
+    "",
+    "static MAX_VERTEX_ATTRIBS: GLint = 16;",
+    "static MAX_TEXTURE_SIZE: GLint = 16384;",
+    "static MAX_COMBINED_TEXTURE_IMAGE_UNITS: GLint = 80;",
+    "",
+    "/// The names generated for one kind of object, and whether each name has been",
+    "/// bound, which is when the object is actually created.",
+    "struct Names {",
+    "    next: GLuint,",
+    "    bound: HashMap<GLuint, bool>,",
+    "}",
+    "",
+    "impl Names {",
+    "    fn new() -> Names {",
+    "        Names { next: 0, bound: HashMap::new() }",
+    "    }",
+    "",
+    "    fn gen(&mut self) -> GLuint {",
+    "        self.next += 1;",
+    "        self.bound.insert(self.next, false);",
+    "        self.next",
+    "    }",
+    "",
+    "    fn delete(&mut self, name: GLuint) -> bool {",
+    "        self.bound.remove(&name)",
+    "    }",
+    "",
+    "    fn is(&self, name: GLuint) -> bool {",
+    "        match self.bound.find(&name) {",
+    "            Some(&bound) => bound,",
+    "            None => false,",
+    "        }",
+    "    }",
+    "",
+    "    fn bind(&mut self, name: GLuint) -> bool {",
+    "        if name == 0 { return true; }",
+    "        match self.bound.find_mut(&name) {",
+    "            Some(bound) => { *bound = true; true }",
+    "            None => false,",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "struct Shader {",
+    "    ty: GLenum,",
+    "    source_len: GLint,",
+    "    compiled: bool,",
+    "}",
+    "",
+    "struct Program {",
+    "    shaders: ~[GLuint],",
+    "    linked: bool,",
+    "    attribs: HashMap<~str, GLint>,",
+    "}",
+    "",
+    "struct State {",
+    "    error: GLenum,",
+    "    next_name: GLuint,",
+    "    buffers: Names,",
+    "    textures: Names,",
+    "    vertex_arrays: Names,",
+    "    framebuffers: Names,",
+    "    shaders: HashMap<GLuint, Shader>,",
+    "    programs: HashMap<GLuint, Program>,",
+    "    buffer_bindings: HashMap<GLenum, GLuint>,",
+    "    buffer_sizes: HashMap<GLuint, GLsizeiptr>,",
+    "    active_texture: GLenum,",
+    "    texture_bindings: HashMap<(GLenum, GLenum), GLuint>,",
+    "    vertex_array: GLuint,",
+    "    enabled_attribs: HashSet<(GLuint, GLuint)>,",
+    "    draw_framebuffer: GLuint,",
+    "    read_framebuffer: GLuint,",
+    "    current_program: GLuint,",
+    "}",
+    "",
+    "impl State {",
+    "    fn new() -> State {",
+    "        State {",
+    "            error: NO_ERROR,",
+    "            next_name: 0,",
+    "            buffers: Names::new(),",
+    "            textures: Names::new(),",
+    "            vertex_arrays: Names::new(),",
+    "            framebuffers: Names::new(),",
+    "            shaders: HashMap::new(),",
+    "            programs: HashMap::new(),",
+    "            buffer_bindings: HashMap::new(),",
+    "            buffer_sizes: HashMap::new(),",
+    "            active_texture: 0x84C0,",
+    "            texture_bindings: HashMap::new(),",
+    "            vertex_array: 0,",
+    "            enabled_attribs: HashSet::new(),",
+    "            draw_framebuffer: 0,",
+    "            read_framebuffer: 0,",
+    "            current_program: 0,",
+    "        }",
+    "    }",
+    "",
+    "    /// Records an error. As in OpenGL, only the first error is kept until it",
+    "    /// is retrieved with `GetError`.",
+    "    fn error(&mut self, error: GLenum) {",
+    "        if self.error == NO_ERROR { self.error = error; }",
+    "    }",
+    "",
+    "    /// Shaders and programs share a namespace",
+    "    fn gen_shader_name(&mut self) -> GLuint {",
+    "        self.next_name += 1;",
+    "        self.next_name",
+    "    }",
+    "",
+    "    fn buffer_binding(&self, target: GLenum) -> GLuint {",
+    "        *self.buffer_bindings.find(&target).unwrap_or(&0)",
+    "    }",
+    "",
+    "    fn texture_binding(&self, target: GLenum) -> GLuint {",
+    "        *self.texture_bindings.find(&(self.active_texture, target)).unwrap_or(&0)",
+    "    }",
+    "}",
+    "",
+    "local_data_key!(state_key: State)",
+    "",
+    "fn with_state<T>(f: &fn(&mut State) -> T) -> T {",
+    "    let mut state = match local_data::pop(state_key) {",
+    "        Some(state) => state,",
+    "        None => State::new(),",
+    "    };",
+    "    let result = f(&mut state);",
+    "    local_data::set(state_key, state);",
+    "    result",
+    "}",
+    "",
+    "/// Discards the emulated objects and bindings of the current task",
+    "pub fn reset() {",
+    "    local_data::pop(state_key);",
+    "}",
+    "",
+    "fn is_buffer_target(target: GLenum) -> bool {",
+    "    match target {",
+    "        0x8892 | 0x8893 | 0x88EB | 0x88EC | 0x8A11 | 0x8C2A | 0x8C8E |",
+    "        0x8F36 | 0x8F37 | 0x8F3F | 0x90D2 | 0x90EE | 0x9192 | 0x92C0 => true,",
+    "        _ => false,",
+    "    }",
+    "}",
+    "",
+    "fn is_texture_target(target: GLenum) -> bool {",
+    "    match target {",
+    "        0x0DE0 | 0x0DE1 | 0x806F | 0x84F5 | 0x8513 | 0x8C18 | 0x8C1A |",
+    "        0x8C2A | 0x9009 | 0x9100 | 0x9102 => true,",
+    "        _ => false,",
+    "    }",
+    "}",
+    "",
+    "unsafe fn gen_names(n: GLsizei, names: *mut GLuint, gen: &fn(&mut State) -> GLuint) {",
+    "    do with_state |st| {",
+    "        if n < 0 {",
+    "            st.error(INVALID_VALUE);",
+    "        } else {",
+    "            for i in range(0, n as int) {",
+    "                *ptr::mut_offset(names, i) = gen(st);",
+    "            }",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "unsafe fn delete_names(n: GLsizei, names: *GLuint, delete: &fn(&mut State, GLuint)) {",
+    "    do with_state |st| {",
+    "        if n < 0 {",
+    "            st.error(INVALID_VALUE);",
+    "        } else {",
+    "            for i in range(0, n as int) {",
+    "                let name = *ptr::offset(names, i);",
+    "                if name != 0 { delete(st, name); }",
+    "            }",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "pub fn GetError() -> GLenum {",
+    "    do with_state |st| {",
+    "        let error = st.error;",
+    "        st.error = NO_ERROR;",
+    "        error",
+    "    }",
+    "}",
+    "",
+    "pub unsafe fn GetIntegerv(pname: GLenum, data: *mut GLint) {",
+    "    do with_state |st| {",
+    "        let value = match pname {",
+    "            0x0D33 => Some(MAX_TEXTURE_SIZE),",
+    "            0x8069 => Some(st.texture_binding(0x0DE1) as GLint),",
+    "            0x84E0 => Some(st.active_texture as GLint),",
+    "            0x85B5 => Some(st.vertex_array as GLint),",
+    "            0x8869 => Some(MAX_VERTEX_ATTRIBS),",
+    "            0x8894 => Some(st.buffer_binding(0x8892) as GLint),",
+    "            0x8895 => Some(st.buffer_binding(0x8893) as GLint),",
+    "            0x8B4D => Some(MAX_COMBINED_TEXTURE_IMAGE_UNITS),",
+    "            0x8B8D => Some(st.current_program as GLint),",
+    "            0x8CA6 => Some(st.draw_framebuffer as GLint),",
+    "            0x8CAA => Some(st.read_framebuffer as GLint),",
+    "            _ => None,",
+    "        };",
+    "        match value {",
+    "            Some(value) => *data = value,",
+    "            None => st.error(INVALID_ENUM),",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "pub unsafe fn GenBuffers(n: GLsizei, buffers: *mut GLuint) {",
+    "    gen_names(n, buffers, |st| st.buffers.gen())",
+    "}",
+    "",
+    "pub unsafe fn DeleteBuffers(n: GLsizei, buffers: *GLuint) {",
+    "    do delete_names(n, buffers) |st, name| {",
+    "        if st.buffers.delete(name) {",
+    "            st.buffer_sizes.remove(&name);",
+    "            for (_, bound) in st.buffer_bindings.mut_iter() {",
+    "                if *bound == name { *bound = 0; }",
+    "            }",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "pub fn IsBuffer(buffer: GLuint) -> GLboolean {",
+    "    with_state(|st| st.buffers.is(buffer) as GLboolean)",
+    "}",
+    "",
+    "pub fn BindBuffer(target: GLenum, buffer: GLuint) {",
+    "    do with_state |st| {",
+    "        if !is_buffer_target(target) {",
+    "            st.error(INVALID_ENUM);",
+    "        } else if !st.buffers.bind(buffer) {",
+    "            st.error(INVALID_OPERATION);",
+    "        } else {",
+    "            st.buffer_bindings.insert(target, buffer);",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "pub unsafe fn BufferData(target: GLenum, size: GLsizeiptr, _: *c_void, usage: GLenum) {",
+    "    do with_state |st| {",
+    "        let buffer = st.buffer_binding(target);",
+    "        match usage {",
+    "            _ if !is_buffer_target(target) => st.error(INVALID_ENUM),",
+    "            0x88E0 | 0x88E1 | 0x88E2 | 0x88E4 | 0x88E5 | 0x88E6 | 0x88E8 | 0x88E9 | 0x88EA => {",
+    "                if size < 0 {",
+    "                    st.error(INVALID_VALUE);",
+    "                } else if buffer == 0 {",
+    "                    st.error(INVALID_OPERATION);",
+    "                } else {",
+    "                    st.buffer_sizes.insert(buffer, size);",
+    "                }",
+    "            }",
+    "            _ => st.error(INVALID_ENUM),",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "pub unsafe fn GenTextures(n: GLsizei, textures: *mut GLuint) {",
+    "    gen_names(n, textures, |st| st.textures.gen())",
+    "}",
+    "",
+    "pub unsafe fn DeleteTextures(n: GLsizei, textures: *GLuint) {",
+    "    do delete_names(n, textures) |st, name| {",
+    "        if st.textures.delete(name) {",
+    "            for (_, bound) in st.texture_bindings.mut_iter() {",
+    "                if *bound == name { *bound = 0; }",
+    "            }",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "pub fn IsTexture(texture: GLuint) -> GLboolean {",
+    "    with_state(|st| st.textures.is(texture) as GLboolean)",
+    "}",
+    "",
+    "pub fn ActiveTexture(texture: GLenum) {",
+    "    do with_state |st| {",
+    "        if texture < 0x84C0 || texture >= 0x84C0 + MAX_COMBINED_TEXTURE_IMAGE_UNITS as GLenum {",
+    "            st.error(INVALID_ENUM);",
+    "        } else {",
+    "            st.active_texture = texture;",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "pub fn BindTexture(target: GLenum, texture: GLuint) {",
+    "    do with_state |st| {",
+    "        if !is_texture_target(target) {",
+    "            st.error(INVALID_ENUM);",
+    "        } else if !st.textures.bind(texture) {",
+    "            st.error(INVALID_OPERATION);",
+    "        } else {",
+    "            let unit = st.active_texture;",
+    "            st.texture_bindings.insert((unit, target), texture);",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "pub unsafe fn GenVertexArrays(n: GLsizei, arrays: *mut GLuint) {",
+    "    gen_names(n, arrays, |st| st.vertex_arrays.gen())",
+    "}",
+    "",
+    "pub unsafe fn DeleteVertexArrays(n: GLsizei, arrays: *GLuint) {",
+    "    do delete_names(n, arrays) |st, name| {",
+    "        if st.vertex_arrays.delete(name) && st.vertex_array == name {",
+    "            st.vertex_array = 0;",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "pub fn IsVertexArray(array: GLuint) -> GLboolean {",
+    "    with_state(|st| st.vertex_arrays.is(array) as GLboolean)",
+    "}",
+    "",
+    "pub fn BindVertexArray(array: GLuint) {",
+    "    do with_state |st| {",
+    "        if st.vertex_arrays.bind(array) {",
+    "            st.vertex_array = array;",
+    "        } else {",
+    "            st.error(INVALID_OPERATION);",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "fn set_vertex_attrib_array(index: GLuint, enabled: bool) {",
+    "    do with_state |st| {",
+    "        if index >= MAX_VERTEX_ATTRIBS as GLuint {",
+    "            st.error(INVALID_VALUE);",
+    "        } else if st.vertex_array == 0 {",
+    "            st.error(INVALID_OPERATION);",
+    "        } else {",
+    "            let attrib = (st.vertex_array, index);",
+    "            if enabled {",
+    "                st.enabled_attribs.insert(attrib);",
+    "            } else {",
+    "                st.enabled_attribs.remove(&attrib);",
+    "            }",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "pub fn EnableVertexAttribArray(index: GLuint) {",
+    "    set_vertex_attrib_array(index, true)",
+    "}",
+    "",
+    "pub fn DisableVertexAttribArray(index: GLuint) {",
+    "    set_vertex_attrib_array(index, false)",
+    "}",
+    "",
+    "pub unsafe fn VertexAttribPointer(index: GLuint, size: GLint, _: GLenum, _: GLboolean, stride: GLsizei, pointer: *c_void) {",
+    "    do with_state |st| {",
+    "        if index >= MAX_VERTEX_ATTRIBS as GLuint || stride < 0 {",
+    "            st.error(INVALID_VALUE);",
+    "        } else if (size < 1 || size > 4) && size != 0x80E1 {",
+    "            st.error(INVALID_VALUE);",
+    "        } else if st.vertex_array == 0 {",
+    "            st.error(INVALID_OPERATION);",
+    "        } else if st.buffer_binding(0x8892) == 0 && !pointer.is_null() {",
+    "            st.error(INVALID_OPERATION);",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "pub fn CreateShader(ty: GLenum) -> GLuint {",
+    "    do with_state |st| {",
+    "        match ty {",
+    "            0x8B30 | 0x8B31 | 0x8DD9 | 0x8E87 | 0x8E88 | 0x91B9 => {",
+    "                let name = st.gen_shader_name();",
+    "                st.shaders.insert(name, Shader { ty: ty, source_len: 0, compiled: false });",
+    "                name",
+    "            }",
+    "            _ => { st.error(INVALID_ENUM); 0 }",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "/// Flags the error for a shader or program name that does not refer to an",
+    "/// object of the expected kind.",
+    "fn shader_error(st: &mut State, name: GLuint, expect_shader: bool) {",
+    "    let other_kind = if expect_shader {",
+    "        st.programs.contains_key(&name)",
+    "    } else {",
+    "        st.shaders.contains_key(&name)",
+    "    };",
+    "    st.error(if other_kind { INVALID_OPERATION } else { INVALID_VALUE });",
+    "}",
+    "",
+    "pub fn DeleteShader(shader: GLuint) {",
+    "    do with_state |st| {",
+    "        if shader != 0 && !st.shaders.remove(&shader) {",
+    "            st.error(INVALID_VALUE);",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "pub fn IsShader(shader: GLuint) -> GLboolean {",
+    "    with_state(|st| st.shaders.contains_key(&shader) as GLboolean)",
+    "}",
+    "",
+    "pub unsafe fn ShaderSource(shader: GLuint, count: GLsizei, string: **GLchar, length: *GLint) {",
+    "    do with_state |st| {",
+    "        if count < 0 {",
+    "            st.error(INVALID_VALUE);",
+    "        } else if !st.shaders.contains_key(&shader) {",
+    "            shader_error(st, shader, true);",
+    "        } else {",
+    "            let mut len = 0;",
+    "            for i in range(0, count as int) {",
+    "                len += if length.is_null() || *ptr::offset(length, i) < 0 {",
+    "                    str::raw::from_c_str(*ptr::offset(string, i)).len() as GLint",
+    "                } else {",
+    "                    *ptr::offset(length, i)",
+    "                };",
+    "            }",
+    "            st.shaders.get_mut(&shader).source_len = len;",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "pub fn CompileShader(shader: GLuint) {",
+    "    do with_state |st| {",
+    "        if st.shaders.contains_key(&shader) {",
+    "            let s = st.shaders.get_mut(&shader);",
+    "            s.compiled = s.source_len > 0;",
+    "        } else {",
+    "            shader_error(st, shader, true);",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "pub unsafe fn GetShaderiv(shader: GLuint, pname: GLenum, params: *mut GLint) {",
+    "    do with_state |st| {",
+    "        if !st.shaders.contains_key(&shader) {",
+    "            shader_error(st, shader, true);",
+    "            return;",
+    "        }",
+    "        let value = {",
+    "            let s = st.shaders.get(&shader);",
+    "            match pname {",
+    "                0x8B4F => Some(s.ty as GLint),",
+    "                0x8B80 => Some(0),",
+    "                0x8B81 => Some(s.compiled as GLint),",
+    "                0x8B84 => Some(0),",
+    "                0x8B88 => Some(if s.source_len > 0 { s.source_len + 1 } else { 0 }),",
+    "                _ => None,",
+    "            }",
+    "        };",
+    "        match value {",
+    "            Some(value) => *params = value,",
+    "            None => st.error(INVALID_ENUM),",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "pub fn CreateProgram() -> GLuint {",
+    "    do with_state |st| {",
+    "        let name = st.gen_shader_name();",
+    "        st.programs.insert(name, Program { shaders: ~[], linked: false, attribs: HashMap::new() });",
+    "        name",
+    "    }",
+    "}",
+    "",
+    "pub fn DeleteProgram(program: GLuint) {",
+    "    do with_state |st| {",
+    "        if program != 0 && !st.programs.remove(&program) {",
+    "            st.error(INVALID_VALUE);",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "pub fn IsProgram(program: GLuint) -> GLboolean {",
+    "    with_state(|st| st.programs.contains_key(&program) as GLboolean)",
+    "}",
+    "",
+    "pub fn AttachShader(program: GLuint, shader: GLuint) {",
+    "    do with_state |st| {",
+    "        if !st.programs.contains_key(&program) {",
+    "            shader_error(st, program, false);",
+    "        } else if !st.shaders.contains_key(&shader) {",
+    "            shader_error(st, shader, true);",
+    "        } else if st.programs.get(&program).shaders.contains(&shader) {",
+    "            st.error(INVALID_OPERATION);",
+    "        } else {",
+    "            st.programs.get_mut(&program).shaders.push(shader);",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "pub fn LinkProgram(program: GLuint) {",
+    "    do with_state |st| {",
+    "        if !st.programs.contains_key(&program) {",
+    "            shader_error(st, program, false);",
+    "        } else {",
+    "            let linked = {",
+    "                let shaders = &st.programs.get(&program).shaders;",
+    "                !shaders.is_empty() && shaders.iter().all(|s| {",
+    "                    st.shaders.find(s).map_default(false, |s| s.compiled)",
+    "                })",
+    "            };",
+    "            let p = st.programs.get_mut(&program);",
+    "            p.linked = linked;",
+    "            p.attribs.clear();",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "pub fn UseProgram(program: GLuint) {",
+    "    do with_state |st| {",
+    "        if program != 0 && !st.programs.contains_key(&program) {",
+    "            shader_error(st, program, false);",
+    "        } else if program != 0 && !st.programs.get(&program).linked {",
+    "            st.error(INVALID_OPERATION);",
+    "        } else {",
+    "            st.current_program = program;",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "pub unsafe fn GetProgramiv(program: GLuint, pname: GLenum, params: *mut GLint) {",
+    "    do with_state |st| {",
+    "        if !st.programs.contains_key(&program) {",
+    "            shader_error(st, program, false);",
+    "            return;",
+    "        }",
+    "        let value = {",
+    "            let p = st.programs.get(&program);",
+    "            match pname {",
+    "                0x8B80 => Some(0),",
+    "                0x8B82 => Some(p.linked as GLint),",
+    "                0x8B84 => Some(0),",
+    "                0x8B85 => Some(p.shaders.len() as GLint),",
+    "                _ => None,",
+    "            }",
+    "        };",
+    "        match value {",
+    "            Some(value) => *params = value,",
+    "            None => st.error(INVALID_ENUM),",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "/// Attribute locations are assigned in the order that they are queried, as the",
+    "/// emulation does not parse GLSL.",
+    "pub unsafe fn GetAttribLocation(program: GLuint, name: *GLchar) -> GLint {",
+    "    let name = str::raw::from_c_str(name);",
+    "    do with_state |st| {",
+    "        if !st.programs.contains_key(&program) {",
+    "            shader_error(st, program, false);",
+    "            -1",
+    "        } else if !st.programs.get(&program).linked {",
+    "            st.error(INVALID_OPERATION);",
+    "            -1",
+    "        } else {",
+    "            let attribs = &mut st.programs.get_mut(&program).attribs;",
+    "            let next = attribs.len() as GLint;",
+    "            *attribs.find_or_insert(name.clone(), next)",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "pub unsafe fn BindFragDataLocation(program: GLuint, _: GLuint, _: *GLchar) {",
+    "    do with_state |st| {",
+    "        if !st.programs.contains_key(&program) {",
+    "            shader_error(st, program, false);",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "pub unsafe fn GenFramebuffers(n: GLsizei, framebuffers: *mut GLuint) {",
+    "    gen_names(n, framebuffers, |st| st.framebuffers.gen())",
+    "}",
+    "",
+    "pub unsafe fn DeleteFramebuffers(n: GLsizei, framebuffers: *GLuint) {",
+    "    do delete_names(n, framebuffers) |st, name| {",
+    "        if st.framebuffers.delete(name) {",
+    "            if st.draw_framebuffer == name { st.draw_framebuffer = 0; }",
+    "            if st.read_framebuffer == name { st.read_framebuffer = 0; }",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "pub fn IsFramebuffer(framebuffer: GLuint) -> GLboolean {",
+    "    with_state(|st| st.framebuffers.is(framebuffer) as GLboolean)",
+    "}",
+    "",
+    "pub fn BindFramebuffer(target: GLenum, framebuffer: GLuint) {",
+    "    do with_state |st| {",
+    "        let (draw, read) = match target {",
+    "            0x8D40 => (true, true),",
+    "            0x8CA9 => (true, false),",
+    "            0x8CA8 => (false, true),",
+    "            _ => { st.error(INVALID_ENUM); return; }",
+    "        };",
+    "        if !st.framebuffers.bind(framebuffer) {",
+    "            st.error(INVALID_OPERATION);",
+    "        } else {",
+    "            if draw { st.draw_framebuffer = framebuffer; }",
+    "            if read { st.read_framebuffer = framebuffer; }",
+    "        }",
+    "    }",
+    "}",
+    "",
+    "/// Attachments are not emulated, so framebuffer objects are always missing",
+    "/// their attachments, while the default framebuffer is complete.",
+    "pub fn CheckFramebufferStatus(target: GLenum) -> GLenum {",
+    "    do with_state |st| {",
+    "        let framebuffer = match target {",
+    "            0x8D40 | 0x8CA9 => st.draw_framebuffer,",
+    "            0x8CA8 => st.read_framebuffer,",
+    "            _ => { st.error(INVALID_ENUM); return 0; }",
+    "        };",
+    "        if framebuffer == 0 { 0x8CD5 } else { 0x8CD7 }",
+    "    }",
+    "}",
+    "",
+    "pub fn DrawArrays(mode: GLenum, first: GLint, count: GLsizei) {",
+    "    do with_state |st| {",
+    "        match mode {",
+    "            0x0000..0x0006 | 0x000A..0x000E => {",
+    "                if first < 0 || count < 0 {",
+    "                    st.error(INVALID_VALUE);",
+    "                } else if st.vertex_array == 0 {",
+    "                    st.error(INVALID_OPERATION);",
+    "                } else if st.draw_framebuffer != 0 {",
+    "                    st.error(INVALID_FRAMEBUFFER_OPERATION);",
+    "                }",
+    "            }",
+    "            _ => st.error(INVALID_ENUM),",
+    "        }",
+    "    }",
+    "}",
+];
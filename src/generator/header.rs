@@ -0,0 +1,223 @@
+// Copyright 2013 The gl-rs developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A backend that writes a C header in the style of `glcorearb.h`, declaring
+//! exactly the enums and commands in the filtered registry.
+
+use std::ascii::StrAsciiExt;
+use std::hashmap::HashSet;
+use std::io::Writer;
+
+use registry::*;
+use Backend;
+
+/// The C definitions of the types used by the GL registry
+pub static GL_TYPEDEFS: &'static [&'static str] = &[
+    "#include <stddef.h>",
+    "#include <stdint.h>",
+    "typedef void GLvoid;",
+    "typedef unsigned int GLenum;",
+    "typedef unsigned char GLboolean;",
+    "typedef unsigned int GLbitfield;",
+    "typedef signed char GLbyte;",
+    "typedef short GLshort;",
+    "typedef int GLint;",
+    "typedef int GLsizei;",
+    "typedef int GLclampx;",
+    "typedef int GLfixed;",
+    "typedef unsigned char GLubyte;",
+    "typedef unsigned short GLushort;",
+    "typedef unsigned int GLuint;",
+    "typedef unsigned short GLhalf;",
+    "typedef unsigned short GLhalfARB;",
+    "typedef unsigned short GLhalfNV;",
+    "typedef float GLfloat;",
+    "typedef float GLclampf;",
+    "typedef double GLdouble;",
+    "typedef double GLclampd;",
+    "typedef char GLchar;",
+    "typedef char GLcharARB;",
+    "typedef ptrdiff_t GLintptr;",
+    "typedef ptrdiff_t GLsizeiptr;",
+    "typedef ptrdiff_t GLintptrARB;",
+    "typedef ptrdiff_t GLsizeiptrARB;",
+    "typedef int64_t GLint64;",
+    "typedef uint64_t GLuint64;",
+    "typedef int64_t GLint64EXT;",
+    "typedef uint64_t GLuint64EXT;",
+    "typedef GLintptr GLvdpauSurfaceNV;",
+    "typedef void *GLeglImageOES;",
+    "#ifdef __APPLE__",
+    "typedef void *GLhandleARB;",
+    "#else",
+    "typedef unsigned int GLhandleARB;",
+    "#endif",
+    "typedef struct __GLsync *GLsync;",
+    "struct _cl_context;",
+    "struct _cl_event;",
+    "typedef void (APIENTRY *GLDEBUGPROC)(GLenum source,GLenum type,GLuint id,GLenum severity,GLsizei length,const GLchar *message,const void *userParam);",
+    "typedef void (APIENTRY *GLDEBUGPROCARB)(GLenum source,GLenum type,GLuint id,GLenum severity,GLsizei length,const GLchar *message,const void *userParam);",
+    "typedef void (APIENTRY *GLDEBUGPROCKHR)(GLenum source,GLenum type,GLuint id,GLenum severity,GLsizei length,const GLchar *message,const void *userParam);",
+    "typedef void (APIENTRY *GLDEBUGPROCAMD)(GLuint id,GLenum category,GLenum severity,GLsizei length,const GLchar *message,void *userParam);",
+];
+
+fn gen_binding(binding: &Binding) -> ~str {
+    if binding.ty.ends_with("*") || binding.ty.ends_with(" ") {
+        binding.ty + binding.ident
+    } else {
+        format!("{} {}", binding.ty, binding.ident)
+    }
+}
+
+pub fn gen_params(cmd: &Cmd) -> ~str {
+    if cmd.params.is_empty() {
+        ~"void"
+    } else {
+        cmd.params.map(|b| gen_binding(b)).connect(", ")
+    }
+}
+
+/// Writes a C header for the filtered registry
+pub struct HeaderBackend {
+    /// A description of the filter, written at the top of the header
+    feature_level: ~str,
+}
+
+impl Backend for HeaderBackend {
+    fn write(&self, writer: @Writer, reg: &Registry, ns: Ns) {
+        write(writer, reg, ns, self.feature_level);
+    }
+}
+
+/// Writes the header for the supplied registry, which must be from the `gl`
+/// namespace.
+pub fn write(writer: @Writer, reg: &Registry, ns: Ns, feature_level: &str) {
+    match ns {
+        Gl => (),
+        ns => fail!("C headers can only be generated for the gl namespace, not {}", ns.to_str()),
+    }
+
+    writer.write_line("/*");
+    writer.write_line(format!(" * OpenGL declarations for {}, generated by glgen.", feature_level));
+    writer.write_line(" */");
+    writer.write_line("");
+    writer.write_line("#ifndef __glgen_h_");
+    writer.write_line("#define __glgen_h_ 1");
+    writer.write_line("");
+    writer.write_line("#ifdef __cplusplus");
+    writer.write_line("extern \"C\" {");
+    writer.write_line("#endif");
+    writer.write_line("");
+    writer.write_line("#if defined(_WIN32) && !defined(APIENTRY) && !defined(__CYGWIN__) && !defined(__SCITECH_SNAP__)");
+    writer.write_line("#ifndef WIN32_LEAN_AND_MEAN");
+    writer.write_line("#define WIN32_LEAN_AND_MEAN 1");
+    writer.write_line("#endif");
+    writer.write_line("#include <windows.h>");
+    writer.write_line("#endif");
+    writer.write_line("");
+    writer.write_line("#ifndef APIENTRY");
+    writer.write_line("#define APIENTRY");
+    writer.write_line("#endif");
+    writer.write_line("#ifndef APIENTRYP");
+    writer.write_line("#define APIENTRYP APIENTRY *");
+    writer.write_line("#endif");
+    writer.write_line("#ifndef GLAPI");
+    writer.write_line("#define GLAPI extern");
+    writer.write_line("#endif");
+    writer.write_line("");
+    for line in GL_TYPEDEFS.iter() {
+        writer.write_line(*line);
+    }
+    writer.write_line("");
+
+    let mut seen = HashSet::new();
+    for e in reg.enums.iter() {
+        if seen.insert(e.ident.clone()) {
+            writer.write_line(format!("\\#define GL_{} {}", e.ident, e.value));
+        }
+    }
+    writer.write_line("");
+
+    let mut seen = HashSet::new();
+    let cmds = reg.cmds.iter().filter(|c| seen.insert(c.proto.ident.clone())).to_owned_vec();
+    for c in cmds.iter() {
+        writer.write_line(format!("typedef {}(APIENTRYP PFNGL{}PROC) ({});",
+                                  if c.proto.ty.ends_with("*") { c.proto.ty.clone() } else { c.proto.ty.trim() + " " },
+                                  c.proto.ident.to_ascii_upper(),
+                                  gen_params(*c)));
+    }
+    writer.write_line("#ifdef GL_GLEXT_PROTOTYPES");
+    for c in cmds.iter() {
+        writer.write_line(format!("GLAPI {} APIENTRY gl{} ({});",
+                                  c.proto.ty.trim(), c.proto.ident, gen_params(*c)));
+    }
+    writer.write_line("#endif");
+    writer.write_line("");
+    writer.write_line("#ifdef __cplusplus");
+    writer.write_line("}");
+    writer.write_line("#endif");
+    writer.write_line("");
+    writer.write_line("#endif");
+}
+
+#[cfg(test)]
+mod tests {
+    use registry::*;
+    use std::io;
+
+    static FIXTURE: &'static str = include_str!("fixtures/registry.xml");
+
+    fn generate(version: &str, profile: &str) -> ~str {
+        let reg = Registry::from_xml(FIXTURE, Gl, Some(Filter {
+            extensions: ~[],
+            profile: profile.to_owned(),
+            version: version.to_owned(),
+            api: ~"gl",
+        }));
+        io::with_str_writer(|w| super::write(w, &reg, Gl, "gl 3.2 core"))
+    }
+
+    fn has_line(output: &str, line: &str) -> bool {
+        output.line_iter().any(|l| l == line)
+    }
+
+    #[test]
+    fn test_enums() {
+        let output = generate("3.2", "core");
+        assert!(has_line(output, "#define GL_TRIANGLES 0x0004"));
+        assert!(has_line(output, "#define GL_2D 0x0600"));
+        assert!(!output.contains("GL_CURRENT_COLOR"));
+    }
+
+    #[test]
+    fn test_cmds() {
+        let output = generate("3.2", "core");
+        assert!(has_line(output, "typedef void (APIENTRYP PFNGLDRAWARRAYSPROC) (GLenum mode, GLint first, GLsizei count);"));
+        assert!(has_line(output, "typedef GLenum (APIENTRYP PFNGLGETERRORPROC) (void);"));
+        assert!(has_line(output, "GLAPI void APIENTRY glBufferData (GLenum target, GLsizeiptr size, const void *data, GLenum usage);"));
+        assert!(has_line(output, "GLAPI GLenum APIENTRY glGetError (void);"));
+        assert!(!output.contains("glColor3f"));
+
+        let output = generate("3.2", "compatibility");
+        assert!(has_line(output, "GLAPI void APIENTRY glColor3fv (const GLfloat *v);"));
+    }
+
+    #[test]
+    #[should_fail]
+    fn test_other_namespaces() {
+        let reg = Registry::from_xml(FIXTURE, Glx, None);
+        io::with_str_writer(|w| super::write(w, &reg, Glx, "glx"));
+    }
+}
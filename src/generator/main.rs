@@ -34,27 +34,68 @@ extern mod extra;
 
 use extra::getopts::groups::*;
 
-use std::hashmap::HashMap;
+use std::ascii::StrAsciiExt;
+use std::hashmap::{HashMap, HashSet};
 use std::io;
+use std::num::from_str_radix;
 use std::os;
 use std::path::Path;
+use std::vec;
 
 use registry::*;
 
+#[cfg(test)]
+mod context;
+#[cfg(test)]
+mod protocol;
+pub mod emulate;
+pub mod header;
+pub mod query;
 pub mod registry;
+pub mod state;
 pub mod ty;
 
 fn main() {
+    run(os::args(), &Backends::builtin());
+}
+
+/// Runs the generator with the supplied command line arguments, writing the
+/// output with one of `backends`. Programs linking to this crate can call this
+/// with their own backends registered alongside the builtin ones.
+pub fn run(args: &[~str], backends: &Backends) {
     let opts = &[
         optopt("", "namespace", "OpenGL namespace (gl by default)", "gl|glx|wgl"),
         optopt("", "api", "API to generate bindings for (gl by default)", "gl|gles1|gles2"),
         optopt("", "profile", "Profile to generate (core by default)", "core|compatability"),
         optopt("", "version", "Version to generate bindings for (4.3 by default)", ""),
+        optopt("", "naming", "Naming style of enums and commands (trimmed by default)", "trimmed|c|snake"),
         optmulti("", "extension", "Extension to include", ""),
+        optmulti("", "overlay", "Extra registry XML to merge with the registry before filtering", "FILE"),
+        optopt("", "generator", "Backend used to write the output (global by default)", backends.names().connect("|")),
+        optflag("", "header", "Write a C header declaring the generated API instead of Rust bindings (same as --generator header)"),
         optflag("", "full", "Generate API for all profiles, versions and extensions"),
+        optflag("", "list-versions", "List the versions of each API in the registry"),
+        optflagopt("", "list-extensions", "List the extensions in the registry, or those supporting an API", "API"),
+        optflag("", "gated", "Generate API for all versions and extensions of --api, gated by cfg flags"),
+        optflag("", "modules", "Put the enums and commands of each extension in their own module"),
+        optopt("", "modules-dir", "Write each extension as its own crate in DIR (implies --modules)", "DIR"),
+        optflag("", "state", "Generate typed getters for the GetPName state variables"),
+        optflag("", "enum-names", "Generate functions for looking up the names of enum values"),
+        optflag("", "errors", "Generate a typed wrapper around GetError"),
+        optflag("", "debug-output", "Generate safe debug output callback registration"),
+        optflag("", "lazy", "Load each function the first time that it is called"),
+        optflag("", "mock", "Generate a mock implementation that records each call"),
+        optflag("", "emulate", "Emulate object state and errors in the mock implementation (implies --mock)"),
+        optflag("", "context-info", "Generate a function describing the version, profile and flags of the current context"),
+        optflag("", "witnesses", "Generate traits for each feature level and extension, handed out once they are verified (implies --context-info)"),
+        optflag("", "array-overloads", "Generate safe wrappers taking fixed-size arrays for the vector forms of commands"),
+        optflag("", "glx-protocol", "Generate encoders for the GLX protocol requests of each command"),
+        optflag("", "stats", "Count the calls made to each command, and optionally time them"),
+        optflag("", "capture", "Generate the capture module for recording and replaying call streams"),
+        optflag("", "atomic", "Store function pointers atomically, so they can be loaded and called from several threads"),
     ];
 
-    let args = match getopts(os::args(), opts) {
+    let args = match getopts(args, opts) {
         Ok(a) => a,
         Err(x) => fail!("Error: {}\n{}", x.to_err_msg(), usage("generator", opts)),
     };
@@ -66,7 +107,12 @@ fn main() {
         ns     => fail2!("Unexpected opengl namespace '{}'", ns)
     };
 
-    let filter = if args.opt_present("full") {
+    // `query <symbol>` describes a symbol instead of generating bindings
+    let free = args.free.slice_from(1);
+    let is_query = !free.is_empty() && free[0].as_slice() == "query";
+    let is_listing = args.opt_present("list-versions") || args.opt_present("list-extensions");
+
+    let filter = if args.opt_present("full") || args.opt_present("gated") || is_query || is_listing {
         None
     } else {
         Some(Filter {
@@ -77,27 +123,525 @@ fn main() {
         })
     };
 
-    let reg = Registry::from_xml(io::file_reader(&path).expect(format!("Could not read {}", path.display())).read_c_str(), ns, filter);
+    let filter_desc = filter.as_ref().map(|f| format!("{} {} {}", f.api, f.version, f.profile));
+
+    let read = |path: &Path| io::file_reader(path).expect(format!("Could not read {}", path.display())).read_c_str();
+    let mut overlays = ~[];
+    for name in args.opt_strs("overlay").move_iter() {
+        let data = read(&Path::new(name.as_slice()));
+        overlays.push((name, data));
+    }
+    let reg = Registry::from_xml_with_overlays(read(&path),
+                                               overlays.map(|&(ref name, ref data)| (name.as_slice(), data.as_slice())),
+                                               ns, filter);
+
+    if is_query {
+        if free.len() != 2 {
+            fail!("Error: expected a symbol to query, such as `query DrawArrays`\n{}", usage("generator", opts));
+        }
+        query::write_symbol(std::io::stdout(), &reg, ns, free[1]);
+        return;
+    }
+    if args.opt_present("list-versions") {
+        query::write_versions(std::io::stdout(), &reg);
+        return;
+    }
+    if args.opt_present("list-extensions") {
+        let api = args.opt_str("list-extensions");
+        query::write_extensions(std::io::stdout(), &reg, api.as_ref().map(|api| api.as_slice()));
+        return;
+    }
+
+    let options = Options {
+        feature_level: match filter_desc {
+            Some(desc) => desc,
+            None => ~"all versions and extensions",
+        },
+        state: args.opt_present("state"),
+        enum_names: args.opt_present("enum-names"),
+        errors: args.opt_present("errors"),
+        debug_output: args.opt_present("debug-output"),
+        lazy: args.opt_present("lazy"),
+        atomic: args.opt_present("atomic"),
+        mock: args.opt_present("mock") || args.opt_present("emulate"),
+        emulate: args.opt_present("emulate"),
+        capture: args.opt_present("capture"),
+        stats: args.opt_present("stats"),
+        context_info: args.opt_present("context-info") || args.opt_present("witnesses"),
+        witnesses: args.opt_present("witnesses"),
+        array_overloads: args.opt_present("array-overloads"),
+        glx_protocol: args.opt_present("glx-protocol"),
+        gated: if args.opt_present("gated") {
+            Some(args.opt_str("api").unwrap_or(~"gl"))
+        } else {
+            None
+        },
+        modules: args.opt_present("modules") || args.opt_present("modules-dir"),
+        modules_dir: args.opt_str("modules-dir"),
+        naming: match args.opt_str("naming").unwrap_or(~"trimmed") {
+            ~"trimmed" => TrimmedNames,
+            ~"c"       => CNames,
+            ~"snake"   => SnakeCaseNames,
+            style      => fail!("Error: unexpected naming style '{}'\n{}", style, usage("generator", opts)),
+        },
+    };
+
+    if options.lazy && options.atomic {
+        fail!("Error: --lazy and --atomic cannot be used together\n{}", usage("generator", opts));
+    }
+    if options.lazy && options.capture {
+        fail!("Error: --lazy and --capture cannot be used together\n{}", usage("generator", opts));
+    }
+    if options.modules && (options.atomic || options.gated.is_some() || options.witnesses || options.lazy
+                           || options.mock || options.stats || options.capture || options.debug_output) {
+        fail!("Error: --modules cannot be used with --atomic, --gated, --witnesses, --lazy, --mock, \
+               --emulate, --stats, --capture or --debug-output\n{}",
+              usage("generator", opts));
+    }
+    if options.gated.is_some() && (options.state || options.debug_output || options.capture
+                                   || options.witnesses || options.array_overloads) {
+        fail!("Error: --gated cannot be used with --state, --debug-output, --capture, --witnesses \
+               or --array-overloads\n{}",
+              usage("generator", opts));
+    }
+
+    let generator = match args.opt_str("generator") {
+        Some(ref name) if args.opt_present("header") && name.as_slice() != "header" => {
+            fail!("Error: --header cannot be used with --generator {}\n{}", *name, usage("generator", opts));
+        }
+        Some(name) => name,
+        None if args.opt_present("header") => ~"header",
+        None => ~"global",
+    };
+    let backend = match backends.select(generator, &options) {
+        Some(backend) => backend,
+        None => fail!("Error: unknown generator '{}'\n{}", generator, usage("generator", opts)),
+    };
+    backend.write(std::io::stdout(), &reg, ns);
+}
+
+/// A flavour of output, written from the filtered registry. New styles of
+/// bindings can be added by implementing this and registering a constructor
+/// for it with `Backends::register`.
+pub trait Backend {
+    /// Writes the output for the supplied registry
+    fn write(&self, writer: @Writer, reg: &Registry, ns: Ns);
+}
+
+/// Rust bindings that load each function into a global static pointer, with
+/// the optional modules selected by the `Options`
+pub struct GlobalBackend {
+    options: Options,
+}
+
+impl Backend for GlobalBackend {
+    fn write(&self, writer: @Writer, reg: &Registry, ns: Ns) {
+        Generator::write(writer, reg, ns, &self.options);
+    }
+}
+
+/// Rust bindings that load the function pointers into the fields of a `Gl`
+/// struct, so that each context can have its own
+pub struct StructBackend {
+    options: Options,
+}
+
+impl Backend for StructBackend {
+    fn write(&self, writer: @Writer, reg: &Registry, ns: Ns) {
+        Generator::write_struct(writer, reg, ns, &self.options);
+    }
+}
+
+/// The backends that can be selected with `--generator`, by name
+pub struct Backends {
+    priv entries: ~[(~str, ~fn(&Options) -> ~Backend)],
+}
+
+impl Backends {
+    /// No backends
+    pub fn new() -> Backends {
+        Backends { entries: ~[] }
+    }
+
+    /// The backends provided by the generator: `global`, `struct` and `header`
+    pub fn builtin() -> Backends {
+        let mut backends = Backends::new();
+        backends.register("global", |options| ~GlobalBackend { options: options.clone() } as ~Backend);
+        backends.register("struct", |options| {
+            options.check_supported("struct", ["--naming"]);
+            ~StructBackend { options: options.clone() } as ~Backend
+        });
+        backends.register("header", |options| {
+            options.check_supported("header", []);
+            ~header::HeaderBackend { feature_level: options.feature_level.clone() } as ~Backend
+        });
+        backends
+    }
+
+    /// Adds a backend, which is constructed from the options when it is
+    /// selected. Fails if a backend with the same name was already registered.
+    pub fn register(&mut self, name: &str, new: ~fn(&Options) -> ~Backend) {
+        if self.entries.iter().any(|&(ref n, _)| n.as_slice() == name) {
+            fail!("A backend named '{}' is already registered", name);
+        }
+        self.entries.push((name.to_owned(), new));
+    }
+
+    /// The names of the registered backends, in the order they were added
+    pub fn names(&self) -> ~[~str] {
+        self.entries.map(|&(ref name, _)| name.clone())
+    }
+
+    /// Returns the backend with the supplied `--generator` name
+    pub fn select(&self, name: &str, options: &Options) -> Option<~Backend> {
+        self.entries.iter()
+            .find(|&&(ref n, _)| n.as_slice() == name)
+            .map(|&(_, ref new)| (*new)(options))
+    }
+}
+
+/// Optional parts of the generated bindings
+#[deriving(Clone)]
+pub struct Options {
+    /// A description of the filter used to generate the bindings, for reporting
+    feature_level: ~str,
+    /// Generate the `state` module of typed `Get*v` queries
+    state: bool,
+    /// Generate `enum_name` and `enum_name_in_group` for reverse lookups
+    enum_names: bool,
+    /// Generate the `GlError` enum along with `get_error` (`check_error` with
+    /// snake case names) and `drain_errors`
+    errors: bool,
+    /// Generate the `debug` module for registering debug output callbacks
+    debug_output: bool,
+    /// Store the loader in `load_with` and resolve each symbol on its first call
+    lazy: bool,
+    /// Store function pointers in atomics, so that loading and calling from
+    /// several threads at once is safe
+    atomic: bool,
+    /// Generate the `mock` module of recording stubs for testing without a context
+    mock: bool,
+    /// Forward the supported mock functions to an emulation of the object model
+    emulate: bool,
+    /// Generate the `capture` module for recording and replaying call streams
+    capture: bool,
+    /// Count the calls to each command in the `stats` module
+    stats: bool,
+    /// Generate `context_info` for querying the version and profile of the
+    /// current context
+    context_info: bool,
+    /// Generate the `features` module of traits for each feature level and
+    /// extension, along with functions that verify them at runtime
+    witnesses: bool,
+    /// Generate the `array` module of wrappers that pass fixed-size arrays to
+    /// the vector forms of commands
+    array_overloads: bool,
+    /// Generate the `protocol` module of GLX request encoders
+    glx_protocol: bool,
+    /// Emit every version and extension of the supplied API, with each enum
+    /// and function gated by the cfg flags of the features that require it
+    gated: Option<~str>,
+    /// Keep only the core enums and commands at the top level, putting those of
+    /// each extension in a module such as `arb::debug_output`
+    modules: bool,
+    /// The directory to write each extension to as its own crate, instead of
+    /// as a module of the bindings
+    modules_dir: Option<~str>,
+    /// The naming style of enums and commands
+    naming: NamingStyle,
+}
+
+impl Options {
+    /// The command line flags that select the options, for reporting the ones
+    /// that a backend does not support
+    pub fn flags(&self) -> ~[&'static str] {
+        let set = [
+            (self.state, "--state"), (self.enum_names, "--enum-names"), (self.errors, "--errors"),
+            (self.debug_output, "--debug-output"), (self.lazy, "--lazy"), (self.atomic, "--atomic"),
+            (self.mock, "--mock"), (self.emulate, "--emulate"), (self.capture, "--capture"),
+            (self.stats, "--stats"), (self.context_info, "--context-info"), (self.witnesses, "--witnesses"),
+            (self.array_overloads, "--array-overloads"), (self.glx_protocol, "--glx-protocol"),
+            (self.gated.is_some(), "--gated"), (self.modules, "--modules"),
+            (match self.naming { TrimmedNames => false, _ => true }, "--naming"),
+        ];
+        set.iter().filter(|&&(enabled, _)| enabled).map(|&(_, flag)| flag).to_owned_vec()
+    }
+
+    /// Fails if an option is set whose flag is not one of the flags supported
+    /// by the named backend
+    pub fn check_supported(&self, backend: &str, supported: &[&str]) {
+        let unsupported = self.flags().move_iter()
+            .filter(|flag| !supported.contains(flag))
+            .to_owned_vec();
+        if !unsupported.is_empty() {
+            fail!("Error: the {} generator does not support {}", backend, unsupported.connect(", "));
+        }
+    }
+}
+
+/// How a parameter is written to a capture stream
+enum CaptureArg {
+    /// A number, written by the `write_*` and read by the `read_*` methods
+    /// with the given suffix, and converted through the given type
+    CaptureScalar(&'static str, &'static str),
+    /// A pointer to an array of the element type, with the given length
+    CaptureBytes(~str, ~str),
+    /// A nul-terminated string
+    CaptureCStr,
+    /// An array of strings with the given length, along with the parameter
+    /// giving the length of each string, if there is one. Strings without a
+    /// length are nul-terminated.
+    CaptureCStrs(~str, Option<~str>),
+    /// A pointer that the command writes to, with an optional length
+    CaptureOutput(~str, Option<~str>),
+}
+
+/// How the enums and commands of the registry are named in the bindings
+#[deriving(Clone, Eq)]
+pub enum NamingStyle {
+    /// The registry names without their namespace prefix, such as `DrawArrays`
+    /// and `TRIANGLES`
+    TrimmedNames,
+    /// The C names, such as `glDrawArrays` and `GL_TRIANGLES`
+    CNames,
+    /// Commands in snake case, such as `draw_arrays`, with trimmed enum names
+    SnakeCaseNames,
+}
+
+/// The keywords and reserved words of Rust, which cannot be used as identifiers
+static KEYWORDS: &'static [&'static str] = &[
+    "abstract", "alignof", "as", "be", "box", "break", "const", "continue",
+    "copy", "crate", "do", "else", "enum", "extern", "false", "final", "fn",
+    "for", "if", "impl", "in", "let", "loop", "macro", "match", "mod", "move",
+    "mut", "offsetof", "once", "override", "priv", "proc", "pub", "pure",
+    "ref", "return", "self", "sizeof", "static", "struct", "super", "trait",
+    "true", "type", "typeof", "unsafe", "unsized", "use", "virtual", "while",
+    "yield",
+];
+
+/// Appends an underscore to identifiers that are Rust keywords.
+fn escape_keyword(ident: &str) -> ~str {
+    if KEYWORDS.iter().any(|kw| *kw == ident) {
+        ident + "_"
+    } else {
+        ident.to_owned()
+    }
+}
+
+/// Makes a name from the registry into a valid identifier, by prefixing names
+/// that start with a digit, such as those of `GL_3DFX_tbuffer`, with an
+/// underscore, and escaping keywords.
+fn escape_ident(ident: &str) -> ~str {
+    if !ident.is_empty() && (ident[0] as char).is_digit() {
+        "_" + ident
+    } else {
+        escape_keyword(ident)
+    }
+}
 
-    Generator::write(std::io::stdout(), &reg, ns);
+/// Converts a camel case command name to snake case, so that `GetIntegerv`
+/// becomes `get_integerv` and `VDPAUInitNV` becomes `vdpau_init_nv`. Digits
+/// stay attached to the preceding word, as in `tex_image2d`.
+fn to_snake_case(ident: &str) -> ~str {
+    let chars = ident.chars().to_owned_vec();
+    let mut s = ~"";
+    for (i, &c) in chars.iter().enumerate() {
+        if i > 0 && c.is_uppercase() {
+            let prev = chars[i - 1];
+            let next_is_lower = i + 1 < chars.len() && chars[i + 1].is_lowercase();
+            if prev.is_lowercase() || (prev.is_uppercase() && next_is_lower) {
+                s.push_char('_');
+            }
+        }
+        s.push_char(c);
+    }
+    s.to_ascii_lower()
 }
 
 static TAB_WIDTH: uint = 4;
 
+/// The source of the `context` module in the generated bindings
+static CONTEXT_SRC: &'static str = include_str!("context.rs");
+
+/// The source of the functions shared by the encoders in `protocol`
+static PROTOCOL_SRC: &'static str = include_str!("protocol.rs");
+
+/// Commands whose GLX protocol is not their parameter list, such as
+/// `DrawArrays`, which sends the enabled client arrays along with its
+/// parameters
+static GLX_SPECIAL_LAYOUTS: &'static [&'static str] = &[
+    "DrawArrays", "DrawArraysEXT",
+];
+
 struct Generator<'self> {
     ns: Ns,
+    options: Options,
     writer: @Writer,
     registry: &'self Registry,
     indent: uint,
+    /// The cfg predicates that each gated enum and command is emitted under,
+    /// keyed by their names in the registry
+    gates: HashMap<~str, ~[~str]>,
+    /// The registry names of the enums and commands required by a feature,
+    /// which stay at the top level when extensions are put in modules
+    core: HashSet<~str>,
+    /// The reason that each enum and command removed from a profile is
+    /// deprecated, keyed by their names in the registry
+    deprecations: HashMap<~str, ~str>,
 }
 
 impl<'self> Generator<'self> {
-    fn new<'a>(writer: @Writer, reg: &'a Registry, ns: Ns) -> Generator<'a> {
+    fn new<'a>(writer: @Writer, reg: &'a Registry, ns: Ns, options: &Options) -> Generator<'a> {
         Generator {
             ns: ns,
+            options: options.clone(),
             writer: writer,
             registry: &'a *reg,
             indent: 0,
+            gates: match options.gated {
+                Some(ref api) => Generator::gen_gates(reg, api.as_slice()),
+                None => HashMap::new(),
+            },
+            core: if options.modules {
+                Generator::gen_core_names(reg)
+            } else {
+                HashSet::new()
+            },
+            deprecations: Generator::gen_deprecations(reg),
+        }
+    }
+
+    /// Finds the enums and commands that a feature removes from a profile,
+    /// along with a note naming the earliest version that removes them.
+    fn gen_deprecations(reg: &Registry) -> HashMap<~str, ~str> {
+        let mut removed = HashMap::new();
+        for f in reg.features.iter().chain(reg.later_features.iter()) {
+            for rem in f.removes.iter() {
+                for name in rem.enums.iter().chain(rem.commands.iter()) {
+                    removed.insert_or_update_with(name.clone(), f,
+                                                  |_, prev| if f.number < prev.number { *prev = f });
+                }
+            }
+        }
+        let mut deprecations = HashMap::new();
+        for (name, f) in removed.iter() {
+            let profiles = f.removes.iter()
+                .filter(|r| r.enums.contains(name) || r.commands.contains(name))
+                .map(|r| r.profile.clone())
+                .to_owned_vec();
+            deprecations.insert(name.clone(), format!("removed from the {} profile in {} {}",
+                                                      profiles.connect(" and "), f.api, f.number));
+        }
+        deprecations
+    }
+
+    /// Writes a `#[deprecated]` attribute if the supplied registry name has
+    /// been removed from a profile
+    fn write_deprecation(&self, name: &str) {
+        match self.deprecations.find_equiv(&name) {
+            Some(note) => self.write_line(format!("\\#[deprecated = \"{}\"]", *note)),
+            None => (),
+        }
+    }
+
+    /// Collects the registry names of the enums and commands that are
+    /// required by one of the features in the registry.
+    fn gen_core_names(reg: &Registry) -> HashSet<~str> {
+        let mut names = HashSet::new();
+        for f in reg.features.iter() {
+            for req in f.requires.iter() {
+                for name in req.enums.iter().chain(req.commands.iter()) {
+                    names.insert(name.clone());
+                }
+            }
+        }
+        names
+    }
+
+    /// Returns false for registry names that are moved into extension modules
+    fn is_core(&self, name: &str) -> bool {
+        !self.options.modules || self.core.contains(&name.to_owned())
+    }
+
+    /// Works out the cfg predicates that each enum and command in the registry
+    /// is available under. An item is available from the first version of the
+    /// API that requires it, except in the core profile from the version that
+    /// removes it, and with any extension that requires it.
+    fn gen_gates(reg: &Registry, api: &str) -> HashMap<~str, ~[~str]> {
+        let mut versions = reg.features.iter()
+            .filter(|f| f.api.as_slice() == api)
+            .map(|f| f.number.clone())
+            .to_owned_vec();
+        extra::sort::quick_sort(versions, |a, b| a <= b);
+
+        let mut added = HashMap::new();
+        let mut removed = HashMap::new();
+        for f in reg.features.iter().filter(|f| f.api.as_slice() == api) {
+            for req in f.requires.iter() {
+                for name in req.enums.iter().chain(req.commands.iter()) {
+                    added.insert_or_update_with(name.clone(), f.number.clone(),
+                                                |_, n| if f.number < *n { *n = f.number.clone() });
+                }
+            }
+            for rem in f.removes.iter().filter(|r| r.profile.as_slice() == "core") {
+                for name in rem.enums.iter().chain(rem.commands.iter()) {
+                    removed.insert_or_update_with(name.clone(), f.number.clone(),
+                                                  |_, n| if f.number < *n { *n = f.number.clone() });
+                }
+            }
+        }
+
+        let mut gates = HashMap::new();
+        for (name, added) in added.iter() {
+            let preds = versions.iter().filter(|v| *v >= added).map(|v| {
+                let version = format!("{}_{}", api, v.replace(".", "_"));
+                match removed.find(name) {
+                    Some(removed) if v >= removed => format!("{}, not({}_core)", version, api),
+                    _ => version,
+                }
+            }).to_owned_vec();
+            gates.insert(name.clone(), preds);
+        }
+        for ext in reg.extensions.iter().filter(|e| e.supported.iter().any(|s| s.as_slice() == api)) {
+            for req in ext.requires.iter() {
+                for name in req.enums.iter().chain(req.commands.iter()) {
+                    gates.find_or_insert(name.clone(), ~[]).push(ext.name.clone());
+                }
+            }
+        }
+        gates
+    }
+
+    fn gen_enum_name(&self, enm: &Enum) -> ~str {
+        (match self.ns {
+            Gl => "GL_",
+            Glx => "GLX_",
+            Wgl => "WGL_",
+        }) + enm.ident
+    }
+
+    /// Writes the cfg attributes that gate the supplied registry name
+    fn write_gate(&self, name: &str) {
+        match self.gates.find_equiv(&name) {
+            Some(preds) => for pred in preds.iter() {
+                self.write_line(format!("\\#[cfg({})]", *pred));
+            },
+            None => (),
+        }
+    }
+
+    /// Returns an expression that is true if the supplied registry name is
+    /// enabled by the cfg flags the bindings are compiled with
+    fn gen_gate_expr(&self, name: &str) -> ~str {
+        match self.gates.find_equiv(&name) {
+            Some(preds) => preds.iter().map(|pred| {
+                match pred.find_str(", not(") {
+                    Some(i) => format!("(cfg!({}) && !cfg!({}))",
+                                       pred.slice_to(i), pred.slice(i + 6, pred.len() - 1)),
+                    None => format!("cfg!({})", *pred),
+                }
+            }).to_owned_vec().connect(" || "),
+            None => ~"true",
         }
     }
 
@@ -131,6 +675,12 @@ impl<'self> Generator<'self> {
     fn for_enums(&self, fn_unseen: &fn(&Enum)) {
         let mut seen = HashMap::new();
         for def in self.registry.enums.iter() {
+            if self.options.gated.is_some() && !self.gates.contains_key(&self.gen_enum_name(def)) {
+                continue;
+            }
+            if !self.is_core(self.gen_enum_name(def)) {
+                continue;
+            }
             match seen.find(&def.ident) {
                 Some(_) => { continue; }
                 None => fn_unseen(def),
@@ -140,13 +690,9 @@ impl<'self> Generator<'self> {
     }
 
     fn write_enum(&self, enm: &Enum, ty: &str) {
-        let ident = if (enm.ident[0] as char).is_digit() {
-            "_" + enm.ident
-        } else {
-            enm.ident.clone()
-        };
+        let ident = self.gen_enum_ident(enm.ident);
 
-        let ty = match ident {
+        let ty = match enm.ident {
             ~"TRUE" | ~"FALSE" => ~"GLboolean",
             _ => ty.to_owned(),
         };
@@ -156,6 +702,8 @@ impl<'self> Generator<'self> {
 
     fn write_enums(&self) {
         do self.for_enums |e| {
+            self.write_deprecation(self.gen_enum_name(e));
+            self.write_gate(self.gen_enum_name(e));
             self.write_enum(e, "GLenum");
         }
     }
@@ -163,6 +711,12 @@ impl<'self> Generator<'self> {
     fn for_cmds(&self, fn_unseen: &fn(&Cmd)) {
         let mut seen = HashMap::new();
         for def in self.registry.cmds.iter() {
+            if self.options.gated.is_some() && !self.gates.contains_key(&Generator::gen_symbol_name(&self.ns, def)) {
+                continue;
+            }
+            if !self.is_core(Generator::gen_symbol_name(&self.ns, def)) {
+                continue;
+            }
             match seen.find(&def.proto.ident) {
                 Some(_) => { continue; }
                 None => fn_unseen(def),
@@ -171,16 +725,119 @@ impl<'self> Generator<'self> {
         }
     }
 
-    fn gen_binding_ident<'a>(binding: &'a Binding, use_idents: bool) -> &'a str {
+    fn gen_binding_ident(binding: &Binding, use_idents: bool) -> ~str {
         if use_idents {
-            match binding.ident.as_slice() {
-                "in" => &'a "in_",
-                "ref" => &'a "ref_",
-                "type" => &'a "type_",
-                ident => ident,
-            }
+            escape_keyword(binding.ident)
         } else {
-            &'a "_"
+            ~"_"
+        }
+    }
+
+    /// The name of a command in the generated bindings, according to the
+    /// naming style
+    fn gen_fn_ident(&self, ident: &str) -> ~str {
+        match self.options.naming {
+            TrimmedNames => ident.to_owned(),
+            CNames => Generator::gen_ns_prefix(&self.ns) + ident,
+            SnakeCaseNames => escape_keyword(to_snake_case(ident)),
+        }
+    }
+
+    fn gen_cmd_ident(&self, cmd: &Cmd) -> ~str {
+        self.gen_fn_ident(cmd.proto.ident)
+    }
+
+    /// The name of an enum in the generated bindings, according to the naming
+    /// style
+    fn gen_enum_ident(&self, ident: &str) -> ~str {
+        match self.options.naming {
+            CNames => format!("{}_{}", Generator::gen_ns_prefix(&self.ns).to_ascii_upper(), ident),
+            _ if (ident[0] as char).is_digit() => "_" + ident,
+            _ => ident.to_owned(),
+        }
+    }
+
+    /// Fails if two enums or two commands in the registry are given the same
+    /// name by the naming style, listing the registry names of each pair.
+    /// The functions and modules written at the top level of the bindings
+    /// alongside the commands, with the option that generates them. Each
+    /// command also has a module of the same name, so these must not be used
+    /// by any command.
+    fn gen_root_helpers(&self) -> ~[(~str, &'static str)] {
+        let mut helpers = ~[
+            (~"load_with", "the loader"), (~"unload", "the loader"),
+            (~"types", "the type aliases"), (~"failing", "the loader"), (~"storage", "the loader"),
+        ];
+        let options = &self.options;
+        let optional = [
+            (options.lazy, ~[~"lazy"], "--lazy"),
+            (options.enum_names, ~[~"enum_name", ~"enum_name_in_group"], "--enum-names"),
+            (options.errors, ~[self.gen_error_fn_ident(), ~"drain_errors"], "--errors"),
+            (options.debug_output, ~[~"debug"], "--debug-output"),
+            (options.mock, ~[~"mock"], "--mock"),
+            (options.capture, ~[~"capture"], "--capture"),
+            (options.stats, ~[~"stats"], "--stats"),
+            (options.context_info, ~[~"context", ~"context_info"], "--context-info"),
+            (options.array_overloads, ~[~"array"], "--array-overloads"),
+            (options.glx_protocol, ~[~"protocol"], "--glx-protocol"),
+            (options.witnesses, ~[~"features"], "--witnesses"),
+            (options.state, ~[~"state"], "--state"),
+        ];
+        for &(enabled, ref names, flag) in optional.iter() {
+            if enabled {
+                for name in names.iter() {
+                    helpers.push((name.clone(), flag));
+                }
+            }
+        }
+        helpers
+    }
+
+    /// The name of the typed wrapper around `GetError`. This is `get_error`,
+    /// unless the naming style already gives that name to `GetError` itself.
+    fn gen_error_fn_ident(&self) -> ~str {
+        let mut taken = false;
+        do self.for_cmds |c| {
+            if self.gen_cmd_ident(c).as_slice() == "get_error" {
+                taken = true;
+            }
+        }
+        if taken { ~"check_error" } else { ~"get_error" }
+    }
+
+    fn check_collisions(&self) {
+        let mut collisions = ~[];
+        let mut enums: HashMap<~str, ~str> = HashMap::new();
+        do self.for_enums |e| {
+            let ident = self.gen_enum_ident(e.ident);
+            match enums.find(&ident) {
+                Some(other) if *other != e.ident => {
+                    collisions.push(format!("{} and {} are both named {}", *other, e.ident, ident));
+                }
+                _ => (),
+            }
+            enums.insert(ident, e.ident.clone());
+        }
+        let mut cmds: HashMap<~str, ~str> = HashMap::new();
+        do self.for_cmds |c| {
+            let ident = self.gen_cmd_ident(c);
+            match cmds.find(&ident) {
+                Some(other) if *other != c.proto.ident => {
+                    collisions.push(format!("{} and {} are both named {}", *other, c.proto.ident, ident));
+                }
+                _ => (),
+            }
+            cmds.insert(ident, c.proto.ident.clone());
+        }
+        for &(helper, flag) in self.gen_root_helpers().iter() {
+            match cmds.find(&helper) {
+                Some(cmd) => collisions.push(format!("{} and the helper of {} are both named {}", *cmd, flag, helper)),
+                None => (),
+            }
+        }
+        if !collisions.is_empty() {
+            fail!("Error: the naming style gives several items the same name:\n    {}",
+                  collisions.connect("\n    "));
         }
     }
 
@@ -215,15 +872,19 @@ impl<'self> Generator<'self> {
         ty::to_return_suffix(ty::to_rust_ty(cmd.proto.ty))
     }
 
-    fn gen_symbol_name(ns: &Ns, cmd: &Cmd) -> ~str {
-        (match *ns {
+    fn gen_ns_prefix(ns: &Ns) -> &'static str {
+        match *ns {
             Gl => "gl",
             Glx => "glx",
             Wgl => "wgl",
-        }) + cmd.proto.ident
+        }
     }
 
-    fn write_header(&self) {
+    fn gen_symbol_name(ns: &Ns, cmd: &Cmd) -> ~str {
+        Generator::gen_ns_prefix(ns) + cmd.proto.ident
+    }
+
+    fn write_licence(&self) {
         self.write_line("// Copyright 2013 The gl-rs developers. For a full listing of the authors,");
         self.write_line("// refer to the AUTHORS file at the top-level directory of this distribution.");
         self.write_line("// ");
@@ -238,6 +899,10 @@ impl<'self> Generator<'self> {
         self.write_line("// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.");
         self.write_line("// See the License for the specific language governing permissions and");
         self.write_line("// limitations under the License.");
+    }
+
+    fn write_header(&self) {
+        self.write_licence();
         self.write_line("");
         self.write_line(format!("\\#[link(name = \"{}\",", self.ns.to_str()));
         self.write_line("       author = \"Brendan Zabarauskas\",");
@@ -250,6 +915,19 @@ impl<'self> Generator<'self> {
         self.write_line("#[feature(macro_rules)];");
         self.write_line("#[feature(globs)];");
         self.write_line("");
+        match self.options.gated {
+            Some(ref api) => {
+                self.write_line(format!("// Select the {} version with a cfg flag such as `--cfg {}_3_2`, the", *api, *api));
+                self.write_line(format!("// core profile with `--cfg {}_core`, and any extensions by name, such", *api));
+                self.write_line("// as `--cfg GL_ARB_debug_output`.");
+                self.write_line("");
+            }
+            None => (),
+        }
+        if self.options.stats {
+            self.write_line("extern mod extra;");
+            self.write_line("");
+        }
         self.write_line("use std::libc::*;");
         self.write_line("use self::types::*;");
     }
@@ -290,6 +968,41 @@ impl<'self> Generator<'self> {
         self.write_line("}");
     }
 
+    fn write_atomic_fnptr_struct_def(&mut self) {
+        self.write_line("/// A function pointer that can be loaded and called from several threads at");
+        self.write_line("/// once. The pointer is stored with `Release` ordering and read with");
+        self.write_line("/// `Acquire` ordering, so a call that races with `load_with` uses either the");
+        self.write_line("/// old or the new function, but never a partially written pointer.");
+        self.write_line("pub struct AtomicFnPtr<F> { ptr: std::unstable::atomics::AtomicUint, failing_fn: F }");
+        self.write_line("");
+        self.write_line("impl<F> AtomicFnPtr<F> {");
+        self.write_line("    #[inline]");
+        self.write_line("    pub fn f(&self) -> F {");
+        self.write_line("        use std::cast::{transmute, transmute_copy};");
+        self.write_line("        use std::unstable::atomics::Acquire;");
+        self.write_line("        match self.ptr.load(Acquire) {");
+        self.write_line("            0 => unsafe { transmute_copy(&self.failing_fn) },");
+        self.write_line("            p => unsafe { transmute_copy(&p) },");
+        self.write_line("        }");
+        self.write_line("    }");
+        self.write_line("");
+        self.write_line("    #[inline]");
+        self.write_line("    pub fn is_loaded(&self) -> bool {");
+        self.write_line("        use std::unstable::atomics::Acquire;");
+        self.write_line("        self.ptr.load(Acquire) != 0");
+        self.write_line("    }");
+        self.write_line("");
+        self.write_line("    pub fn store(&mut self, ptr: Option<extern \"C\" fn()>) -> bool {");
+        self.write_line("        use std::cast::transmute;");
+        self.write_line("        use std::unstable::atomics::Release;");
+        self.write_line("        match ptr {");
+        self.write_line("            Some(p) => { self.ptr.store(unsafe { transmute(p) }, Release); true }");
+        self.write_line("            None => { self.ptr.store(0, Release); false }");
+        self.write_line("        }");
+        self.write_line("    }");
+        self.write_line("}");
+    }
+
     fn write_failing_fns(&mut self) {
         self.write_line("mod failing {");
         self.incr_indent();
@@ -306,7 +1019,7 @@ impl<'self> Generator<'self> {
         self.for_cmds(
             |c| self.write_line(format!(
                 "failing!(fn {}({}){})",
-                c.proto.ident,
+                self.gen_cmd_ident(c),
                 Generator::gen_param_ty_list(c),
                 Generator::gen_return_suffix(c)
             ))
@@ -316,46 +1029,76 @@ impl<'self> Generator<'self> {
     }
 
     fn write_fns(&self) {
-        self.for_cmds(
-            |c| self.write_line(format!(
-                "\\#[fixed_stack_segment] \\#[inline] pub {}fn {}({}){} \\{ {}(storage::{}.f)({}){} \\}",
+        let mut id = 0u;
+        do self.for_cmds |c| {
+            let call = format!("{}(storage::{}.{})({}){}",
+                if !c.is_safe { "" } else { "unsafe { " },
+                self.gen_cmd_ident(c),
+                if self.options.atomic { "f()" } else { "f" },
+                Generator::gen_param_ident_list(c),
+                if !c.is_safe { "" } else { " }" });
+            self.write_deprecation(Generator::gen_symbol_name(&self.ns, c));
+            self.write_gate(Generator::gen_symbol_name(&self.ns, c));
+            self.write_line(format!(
+                "\\#[fixed_stack_segment] \\#[inline] pub {}fn {}({}){} \\{ {} \\}",
                 if c.is_safe { "" } else { "unsafe " },
-                c.proto.ident,
+                self.gen_cmd_ident(c),
                 Generator::gen_param_list(c, true),
                 Generator::gen_return_suffix(c),
-                if !c.is_safe { "" } else { "unsafe { " },
-                c.proto.ident,
-                Generator::gen_param_ident_list(c),
-                if !c.is_safe { "" } else { " }" }
-            ))
-        );
+                if self.options.stats {
+                    format!("let stats_start = stats::start(); let ret = {}; stats::record({}, stats_start); ret", call, id)
+                } else {
+                    call
+                }
+            ));
+            id += 1;
+        }
     }
 
     fn write_ptrs(&mut self) {
+        let stub = self.gen_stub_mod();
         self.write_line("mod storage {");
         self.incr_indent();
         self.write_line("use std::libc::*;");
+        if self.options.atomic {
+            self.write_line("use std::unstable::atomics::INIT_ATOMIC_UINT;");
+        }
         self.write_line("use super::types::*;");
         self.write_line("");
         self.write_line("macro_rules! fn_ptr(");
-        self.write_line("    (fn $name:ident()) => (");
-        self.write_line("        pub static mut $name: ::FnPtr<extern \"C\" fn()> = ::FnPtr { f: ::failing::$name, is_loaded: false };");
-        self.write_line("    );");
-        self.write_line("    (fn $name:ident() -> $ret_ty:ty) => (");
-        self.write_line("        pub static mut $name: ::FnPtr<extern \"C\" fn() -> $ret_ty> = ::FnPtr { f: ::failing::$name, is_loaded: false };");
-        self.write_line("    );");
-        self.write_line("    (fn $name:ident($($arg:ident : $arg_ty:ty),*)) => (");
-        self.write_line("        pub static mut $name: ::FnPtr<extern \"C\" fn($($arg: $arg_ty),*)> = ::FnPtr { f: ::failing::$name, is_loaded: false };");
-        self.write_line("    );");
-        self.write_line("    (fn $name:ident($($arg:ident : $arg_ty:ty),*) -> $ret_ty:ty) => (");
-        self.write_line("        pub static mut $name: ::FnPtr<extern \"C\" fn($($arg: $arg_ty),*) -> $ret_ty> = ::FnPtr { f: ::failing::$name, is_loaded: false };");
-        self.write_line("    );");
+        if self.options.atomic {
+            self.write_line("    (fn $name:ident()) => (");
+            self.write_line("        pub static mut $name: ::AtomicFnPtr<extern \"C\" fn()> = ::AtomicFnPtr { ptr: INIT_ATOMIC_UINT, failing_fn: ::failing::$name };");
+            self.write_line("    );");
+            self.write_line("    (fn $name:ident() -> $ret_ty:ty) => (");
+            self.write_line("        pub static mut $name: ::AtomicFnPtr<extern \"C\" fn() -> $ret_ty> = ::AtomicFnPtr { ptr: INIT_ATOMIC_UINT, failing_fn: ::failing::$name };");
+            self.write_line("    );");
+            self.write_line("    (fn $name:ident($($arg:ident : $arg_ty:ty),*)) => (");
+            self.write_line("        pub static mut $name: ::AtomicFnPtr<extern \"C\" fn($($arg: $arg_ty),*)> = ::AtomicFnPtr { ptr: INIT_ATOMIC_UINT, failing_fn: ::failing::$name };");
+            self.write_line("    );");
+            self.write_line("    (fn $name:ident($($arg:ident : $arg_ty:ty),*) -> $ret_ty:ty) => (");
+            self.write_line("        pub static mut $name: ::AtomicFnPtr<extern \"C\" fn($($arg: $arg_ty),*) -> $ret_ty> = ::AtomicFnPtr { ptr: INIT_ATOMIC_UINT, failing_fn: ::failing::$name };");
+            self.write_line("    );");
+        } else {
+            self.write_line("    (fn $name:ident()) => (");
+            self.write_line(format!("        pub static mut $name: ::FnPtr<extern \"C\" fn()> = ::FnPtr \\{ f: ::{}::$name, is_loaded: false \\};", stub));
+            self.write_line("    );");
+            self.write_line("    (fn $name:ident() -> $ret_ty:ty) => (");
+            self.write_line(format!("        pub static mut $name: ::FnPtr<extern \"C\" fn() -> $ret_ty> = ::FnPtr \\{ f: ::{}::$name, is_loaded: false \\};", stub));
+            self.write_line("    );");
+            self.write_line("    (fn $name:ident($($arg:ident : $arg_ty:ty),*)) => (");
+            self.write_line(format!("        pub static mut $name: ::FnPtr<extern \"C\" fn($($arg: $arg_ty),*)> = ::FnPtr \\{ f: ::{}::$name, is_loaded: false \\};", stub));
+            self.write_line("    );");
+            self.write_line("    (fn $name:ident($($arg:ident : $arg_ty:ty),*) -> $ret_ty:ty) => (");
+            self.write_line(format!("        pub static mut $name: ::FnPtr<extern \"C\" fn($($arg: $arg_ty),*) -> $ret_ty> = ::FnPtr \\{ f: ::{}::$name, is_loaded: false \\};", stub));
+            self.write_line("    );");
+        }
         self.write_line(")");
         self.write_line("");
         self.for_cmds(
             |c| self.write_line(format!(
                 "fn_ptr!(fn {}({}){})",
-                c.proto.ident,
+                self.gen_cmd_ident(c),
                 Generator::gen_param_list(c, true),
                 Generator::gen_return_suffix(c)
             ))
@@ -364,17 +1107,51 @@ impl<'self> Generator<'self> {
         self.write_line("}");
     }
 
+    /// The module containing the functions that the pointers in `storage` are
+    /// initialised with.
+    fn gen_stub_mod(&self) -> &'static str {
+        if self.options.lazy { "lazy" } else { "failing" }
+    }
+
     fn write_fn_mods(&self) {
         self.write_line("macro_rules! fn_mod(");
         self.write_line("    ($name:ident, $sym:expr) => (");
         self.write_line("        pub mod $name {");
         self.write_line("            #[inline]");
-        self.write_line("            pub fn is_loaded() -> bool { unsafe { ::storage::$name.is_loaded } }");
+        if self.options.lazy {
+            self.write_line("            pub fn is_loaded() -> bool {");
+            self.write_line("                unsafe {");
+            self.write_line("                    if !::storage::$name.is_loaded {");
+            self.write_line("                        ::storage::$name = ::FnPtr::new(::lazy::load($sym), ::lazy::$name);");
+            self.write_line("                    }");
+            self.write_line("                    ::storage::$name.is_loaded");
+            self.write_line("                }");
+            self.write_line("            }");
+        } else if self.options.atomic {
+            self.write_line("            pub fn is_loaded() -> bool { unsafe { ::storage::$name.is_loaded() } }");
+        } else {
+            self.write_line("            pub fn is_loaded() -> bool { unsafe { ::storage::$name.is_loaded } }");
+        }
         self.write_line("            ");
         self.write_line("            #[inline]");
-        self.write_line("            pub fn load_with(loadfn: &fn(symbol: &str) -> Option<extern \"C\" fn()>) {");
-        self.write_line("                unsafe { ::storage::$name = ::FnPtr::new(loadfn($sym), ::failing::$name) }");
+        self.write_line("            pub fn load_with(loadfn: &fn(symbol: &str) -> Option<extern \"C\" fn()>) -> bool {");
+        if self.options.atomic {
+            self.write_line("                unsafe { ::storage::$name.store(loadfn($sym)) }");
+        } else {
+            self.write_line("                unsafe {");
+            self.write_line("                    ::storage::$name = ::FnPtr::new(loadfn($sym), ::failing::$name);");
+            self.write_line("                    ::storage::$name.is_loaded");
+            self.write_line("                }");
+        }
         self.write_line("            }");
+        self.write_line("            ");
+        self.write_line("            #[inline]");
+        if self.options.atomic {
+            self.write_line("            pub fn unload() { unsafe { ::storage::$name.store(None); } }");
+        } else {
+            self.write_line(format!("            pub fn unload() \\{ unsafe \\{ ::storage::$name = ::FnPtr \\{ f: ::{}::$name, is_loaded: false \\} \\} \\}",
+                                    self.gen_stub_mod()));
+        }
         self.write_line("        }");
         self.write_line("    )");
         self.write_line(")");
@@ -382,33 +1159,2043 @@ impl<'self> Generator<'self> {
         self.for_cmds(
             |c| self.write_line(format!(
                 "fn_mod!({}, \"{}\")",
-                c.proto.ident,
+                self.gen_cmd_ident(c),
                 Generator::gen_symbol_name(&self.ns, c)))
         );
     }
 
-    fn write_load_fn(&mut self) {
-        self.write_line("/// Load each OpenGL symbol using a custom load function. This allows for the");
-        self.write_line("/// use of functions like `glfwGetProcAddress` or `SDL_GL_GetProcAddress`.");
+    fn write_lazy_fns(&mut self) {
+        self.write_line("mod lazy {");
+        self.incr_indent();
+        self.write_line("use std::cast;");
+        self.write_line("use std::libc::*;");
+        self.write_line("use super::types::*;");
+        self.write_line("");
+        self.write_line("type Loader = ~fn(symbol: &str) -> Option<extern \"C\" fn()>;");
+        self.write_line("");
+        self.write_line("// The boxed loader supplied to `load_with`");
+        self.write_line("static mut LOADER: *c_void = 0 as *c_void;");
+        self.write_line("");
+        self.write_line("pub fn set_loader(loadfn: Loader) {");
+        self.write_line("    clear_loader();");
+        self.write_line("    unsafe {");
+        self.write_line("        let boxed: ~Loader = ~loadfn;");
+        self.write_line("        LOADER = cast::transmute(boxed);");
+        self.write_line("    }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("pub fn clear_loader() {");
+        self.write_line("    unsafe {");
+        self.write_line("        if !LOADER.is_null() {");
+        self.write_line("            let _: ~Loader = cast::transmute(LOADER);");
+        self.write_line("            LOADER = 0 as *c_void;");
+        self.write_line("        }");
+        self.write_line("    }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("pub fn load(symbol: &str) -> Option<extern \"C\" fn()> {");
+        self.write_line("    unsafe {");
+        self.write_line("        if LOADER.is_null() { return None; }");
+        self.write_line("        let loader: &Loader = cast::transmute(LOADER);");
+        self.write_line("        (*loader)(symbol)");
+        self.write_line("    }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("// Resolves the symbol on the first call, replacing itself in `storage` with");
+        self.write_line("// either the real function or the failing stub.");
+        self.write_line("macro_rules! lazy(");
+        self.write_line("    (fn $name:ident(), $sym:expr) => (");
+        self.write_line("        pub extern \"C\" fn $name() {");
+        self.write_line("            unsafe {");
+        self.write_line("                ::storage::$name = ::FnPtr::new(load($sym), ::failing::$name);");
+        self.write_line("                (::storage::$name.f)()");
+        self.write_line("            }");
+        self.write_line("        }");
+        self.write_line("    );");
+        self.write_line("    (fn $name:ident() -> $ret_ty:ty, $sym:expr) => (");
+        self.write_line("        pub extern \"C\" fn $name() -> $ret_ty {");
+        self.write_line("            unsafe {");
+        self.write_line("                ::storage::$name = ::FnPtr::new(load($sym), ::failing::$name);");
+        self.write_line("                (::storage::$name.f)()");
+        self.write_line("            }");
+        self.write_line("        }");
+        self.write_line("    );");
+        self.write_line("    (fn $name:ident($($arg:ident : $arg_ty:ty),*), $sym:expr) => (");
+        self.write_line("        pub extern \"C\" fn $name($($arg: $arg_ty),*) {");
+        self.write_line("            unsafe {");
+        self.write_line("                ::storage::$name = ::FnPtr::new(load($sym), ::failing::$name);");
+        self.write_line("                (::storage::$name.f)($($arg),*)");
+        self.write_line("            }");
+        self.write_line("        }");
+        self.write_line("    );");
+        self.write_line("    (fn $name:ident($($arg:ident : $arg_ty:ty),*) -> $ret_ty:ty, $sym:expr) => (");
+        self.write_line("        pub extern \"C\" fn $name($($arg: $arg_ty),*) -> $ret_ty {");
+        self.write_line("            unsafe {");
+        self.write_line("                ::storage::$name = ::FnPtr::new(load($sym), ::failing::$name);");
+        self.write_line("                (::storage::$name.f)($($arg),*)");
+        self.write_line("            }");
+        self.write_line("        }");
+        self.write_line("    );");
+        self.write_line(")");
+        self.write_line("");
+        self.for_cmds(
+            |c| self.write_line(format!(
+                "lazy!(fn {}({}){}, \"{}\")",
+                self.gen_cmd_ident(c),
+                Generator::gen_param_list(c, true),
+                Generator::gen_return_suffix(c),
+                Generator::gen_symbol_name(&self.ns, c)
+            ))
+        );
+        self.decr_indent();
+        self.write_line("}");
+    }
+
+    fn write_lazy_load_fn(&mut self) {
+        self.write_line("/// Stores the load function, which will be used to load each OpenGL symbol");
+        self.write_line("/// the first time that it is called. This allows for the use of functions");
+        self.write_line("/// like `glfwGetProcAddress` or `SDL_GL_GetProcAddress`.");
         self.write_line("///");
         self.write_line("/// ~~~");
-        self.write_line("/// let gl = gl::load_with(glfw::get_proc_address);");
+        self.write_line("/// gl::load_with(glfw::get_proc_address);");
         self.write_line("/// ~~~");
-        self.write_line("pub fn load_with(loadfn: &fn(symbol: &str) -> Option<extern \"C\" fn()>) {");
+        self.write_line("pub fn load_with(loadfn: ~fn(symbol: &str) -> Option<extern \"C\" fn()>) {");
+        self.incr_indent();
+        self.write_line("lazy::set_loader(loadfn);");
+        self.write_line("unsafe {");
         self.incr_indent();
         self.for_cmds(
-            |c| self.write_line(format!("{}::load_with(|s| loadfn(s));", c.proto.ident))
+            |c| self.write_line(format!("storage::{} = FnPtr \\{ f: lazy::{}, is_loaded: false \\};",
+                                        self.gen_cmd_ident(c), self.gen_cmd_ident(c)))
         );
         self.decr_indent();
         self.write_line("}");
+        self.decr_indent();
+        self.write_line("}");
     }
 
-    fn write(writer: @Writer, reg: &Registry, ns: Ns) {
-        let mut gen = Generator::new(writer, reg, ns);
-
-        // header with licence, metadata and imports
-        gen.write_header();
-        gen.write_line("");
+    fn write_unload_fn(&mut self) {
+        self.write_line("/// Restores every function pointer to its unloaded state, so that calling a");
+        self.write_line("/// function fails instead of using a stale driver pointer. This should be");
+        self.write_line("/// called when the context that the functions were loaded from is destroyed.");
+        self.write_line("pub fn unload() {");
+        self.incr_indent();
+        if self.options.lazy {
+            self.write_line("lazy::clear_loader();");
+        }
+        self.for_cmds(
+            |c| self.write_line(format!("{}::unload();", self.gen_cmd_ident(c)))
+        );
+        if self.options.modules && self.options.modules_dir.is_none() {
+            for ext in self.registry.extensions.iter() {
+                let (vendor, name) = Generator::gen_extension_mod_path(ext.name);
+                self.write_line(format!("{}::{}::unload();", vendor, name));
+            }
+        }
+        self.decr_indent();
+        self.write_line("}");
+    }
+
+    fn write_load_report_struct_def(&mut self) {
+        self.write_line("/// The symbols that were found and missing during a call to `load_with`");
+        self.write_line("pub struct LoadReport {");
+        self.write_line("    /// The feature level that the bindings were generated for");
+        self.write_line("    requested: &'static str,");
+        self.write_line("    loaded: ~[&'static str],");
+        self.write_line("    missing: ~[&'static str],");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("impl LoadReport {");
+        self.write_line("    fn record(&mut self, symbol: &'static str, is_loaded: bool) {");
+        self.write_line("        if is_loaded { self.loaded.push(symbol) } else { self.missing.push(symbol) }");
+        self.write_line("    }");
+        self.write_line("");
+        self.write_line("    /// Returns `true` if every symbol was loaded");
+        self.write_line("    pub fn is_complete(&self) -> bool {");
+        self.write_line("        self.missing.is_empty()");
+        self.write_line("    }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("impl ToStr for LoadReport {");
+        self.write_line("    fn to_str(&self) -> ~str {");
+        self.write_line("        if self.is_complete() {");
+        self.write_line("            format!(\"{} requested, all {} commands loaded\",");
+        self.write_line("                    self.requested, self.loaded.len())");
+        self.write_line("        } else {");
+        self.write_line("            format!(\"{} requested, {} commands missing: {}\",");
+        self.write_line("                    self.requested, self.missing.len(), self.missing.connect(\", \"))");
+        self.write_line("        }");
+        self.write_line("    }");
+        self.write_line("}");
+    }
+
+    fn write_load_fn(&mut self) {
+        self.write_line("/// Load each OpenGL symbol using a custom load function. This allows for the");
+        self.write_line("/// use of functions like `glfwGetProcAddress` or `SDL_GL_GetProcAddress`.");
+        self.write_line("/// Returns a report of the symbols that could not be loaded.");
+        self.write_line("///");
+        self.write_line("/// ~~~");
+        self.write_line("/// let report = gl::load_with(glfw::get_proc_address);");
+        self.write_line("/// if !report.is_complete() { fail!(report.to_str()) }");
+        self.write_line("/// ~~~");
+        self.write_line("pub fn load_with(loadfn: &fn(symbol: &str) -> Option<extern \"C\" fn()>) -> LoadReport {");
+        self.incr_indent();
+        self.write_line(format!("let mut report = LoadReport \\{ requested: \"{}\", loaded: ~[], missing: ~[] \\};",
+                                self.options.feature_level));
+        self.for_cmds(
+            |c| {
+                let symbol = Generator::gen_symbol_name(&self.ns, c);
+                let record = format!("report.record(\"{}\", {}::load_with(|s| loadfn(s)));",
+                                     symbol, self.gen_cmd_ident(c));
+                if self.options.gated.is_some() {
+                    // skip the symbols that are not part of the selected feature level
+                    self.write_line(format!("if {} \\{", self.gen_gate_expr(symbol)));
+                    self.write_line("    " + record);
+                    self.write_line("}");
+                } else {
+                    self.write_line(record);
+                }
+            }
+        );
+        self.write_line("report");
+        self.decr_indent();
+        self.write_line("}");
+    }
+
+    fn has_enum(&self, ident: &str) -> bool {
+        self.registry.enums.iter().any(|e| e.ident.as_slice() == ident && self.is_core(self.gen_enum_name(e)))
+    }
+
+    fn has_cmd(&self, ident: &str) -> bool {
+        self.registry.cmds.iter().any(|c| {
+            c.proto.ident.as_slice() == ident && self.is_core(Generator::gen_symbol_name(&self.ns, c))
+        })
+    }
+
+    /// Collects the members of the `GetPName` group that are present in the
+    /// registry and have a known type, along with their query command.
+    fn state_vars(&self) -> ~[(~str, state::StateTy, uint)] {
+        let mut vars = ~[];
+        let mut seen = HashMap::new();
+        for group in self.registry.groups.iter().filter(|g| g.name.as_slice() == "GetPName") {
+            for name in group.enums.iter() {
+                let ident = if name.starts_with("GL_") { name.slice_from(3).to_owned() } else { name.clone() };
+                if seen.contains_key(&ident) || !self.has_enum(ident) { continue; }
+                match state::pname_info(ident) {
+                    Some((ty, count)) if self.has_cmd(ty.query_cmd()) => {
+                        seen.insert(ident.clone(), ());
+                        vars.push((ident, ty, count));
+                    }
+                    _ => debug2!("Skipping state variable {}", ident),
+                }
+            }
+        }
+        vars
+    }
+
+    fn gen_state_fn_name(ident: &str, ty: state::StateTy) -> ~str {
+        match ty {
+            state::Capability => ident.to_ascii_lower() + "_enabled",
+            _ => ident.to_ascii_lower(),
+        }
+    }
+
+    fn gen_state_ret_ty(ty: state::StateTy, count: uint) -> ~str {
+        if count == 1 {
+            ty.rust_ty().to_owned()
+        } else {
+            format!("[{}, ..{}]", ty.rust_ty(), count)
+        }
+    }
+
+    fn write_state_fn(&mut self, ident: &str, ty: state::StateTy, count: uint) {
+        self.write_doc_comment(format!("Queries `GL_{}` using `{}`.", ident, ty.query_cmd()));
+        self.write_line(format!("pub fn {}() -> {} \\{",
+            Generator::gen_state_fn_name(ident, ty),
+            Generator::gen_state_ret_ty(ty, count)));
+        self.incr_indent();
+        if count == 1 {
+            self.write_line(format!("let mut value: {} = {};", ty.gl_ty(), ty.zero()));
+            self.write_line(format!("unsafe \\{ ::{}(::{}, &mut value) \\}",
+                self.gen_fn_ident(ty.query_cmd()), self.gen_enum_ident(ident)));
+            self.write_line(match ty {
+                state::Boolean | state::Capability => format!("value == ::{}", self.gen_enum_ident("TRUE")),
+                _ => ~"value",
+            });
+        } else {
+            self.write_line(format!("let mut values: [{}, ..{}] = [{}, ..{}];",
+                ty.gl_ty(), count, ty.zero(), count));
+            self.write_line(format!("unsafe \\{ ::{}(::{}, &mut values[0]) \\}",
+                self.gen_fn_ident(ty.query_cmd()), self.gen_enum_ident(ident)));
+            match ty {
+                state::Boolean | state::Capability => {
+                    let elems = range(0, count)
+                        .map(|i| format!("values[{}] == ::{}", i, self.gen_enum_ident("TRUE")))
+                        .to_owned_vec()
+                        .connect(", ");
+                    self.write_line(format!("[{}]", elems));
+                }
+                _ => self.write_line("values"),
+            }
+        }
+        self.decr_indent();
+        self.write_line("}");
+    }
+
+    fn write_state_fns(&mut self) {
+        let vars = self.state_vars();
+        let limits = vars.iter().filter(|&&(ref ident, _, _)| state::is_limit(*ident)).to_owned_vec();
+
+        self.write_line("/// Typed getters for the `GetPName` state variables. These must only be");
+        self.write_line("/// called once the corresponding `Get*v` functions have been loaded.");
+        self.write_line("pub mod state {");
+        self.incr_indent();
+        self.write_line("use super::types::*;");
+        self.write_line("");
+        for &(ref ident, ty, count) in vars.iter() {
+            self.write_state_fn(*ident, ty, count);
+            self.write_line("");
+        }
+
+        self.write_line("/// Every implementation-dependent limit exposed by the context. This can be");
+        self.write_line("/// printed with `{:?}` for inclusion in bug reports.");
+        self.write_line("#[deriving(Clone)]");
+        self.write_line("pub struct Limits {");
+        self.incr_indent();
+        for &&(ref ident, ty, count) in limits.iter() {
+            self.write_line(format!("{}: {},",
+                Generator::gen_state_fn_name(*ident, ty),
+                Generator::gen_state_ret_ty(ty, count)));
+        }
+        self.decr_indent();
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("/// Queries every implementation-dependent limit at once.");
+        self.write_line("pub fn limits() -> Limits {");
+        self.incr_indent();
+        self.write_line("Limits {");
+        self.incr_indent();
+        for &&(ref ident, ty, _) in limits.iter() {
+            let name = Generator::gen_state_fn_name(*ident, ty);
+            self.write_line(format!("{}: {}(),", name, name));
+        }
+        self.decr_indent();
+        self.write_line("}");
+        self.decr_indent();
+        self.write_line("}");
+        self.decr_indent();
+        self.write_line("}");
+    }
+
+    /// Parses the value of an enum definition, returning `None` if it does not
+    /// fit in a `GLenum`.
+    fn parse_enum_value(value: &str) -> Option<u32> {
+        let value = if value.starts_with("0x") {
+            from_str_radix::<u64>(value.slice_from(2), 16)
+        } else {
+            from_str::<u64>(value)
+        };
+        match value {
+            Some(v) if v <= 0xFFFFFFFF => Some(v as u32),
+            _ => None,
+        }
+    }
+
+    fn write_enum_name_fns(&mut self) {
+        self.write_line("/// Returns the name of an enum value, without the `GL_` prefix. Values that are");
+        self.write_line("/// shared between several enums, such as `0x0001`, return the name of the");
+        self.write_line("/// first definition in the registry. Use `enum_name_in_group` if the group of");
+        self.write_line("/// the value is known.");
+        self.write_line("pub fn enum_name(value: GLenum) -> Option<&'static str> {");
+        self.incr_indent();
+        self.write_line("match value {");
+        self.incr_indent();
+        let mut seen = HashMap::new();
+        do self.for_enums |e| {
+            match Generator::parse_enum_value(e.value) {
+                Some(v) if !seen.contains_key(&v) => {
+                    seen.insert(v, ());
+                    self.write_line(format!("{} => Some(\"{}\"),", e.value, e.ident));
+                }
+                _ => (),
+            }
+        }
+        self.write_line("_ => None,");
+        self.decr_indent();
+        self.write_line("}");
+        self.decr_indent();
+        self.write_line("}");
+        self.write_line("");
+
+        self.write_line("/// Returns the name of an enum value that is a member of the named group, for");
+        self.write_line("/// example `enum_name_in_group(\"PrimitiveType\", 0x0000)` returns `POINTS`.");
+        self.write_line("pub fn enum_name_in_group(group: &str, value: GLenum) -> Option<&'static str> {");
+        self.incr_indent();
+        self.write_line("match (group, value) {");
+        self.incr_indent();
+        for group in self.registry.groups.iter() {
+            let mut seen = HashMap::new();
+            for name in group.enums.iter() {
+                let ident = if name.starts_with("GL_") { name.slice_from(3) } else { name.as_slice() };
+                match self.registry.enums.iter().find(|e| e.ident.as_slice() == ident) {
+                    Some(e) => match Generator::parse_enum_value(e.value) {
+                        Some(v) if !seen.contains_key(&v) => {
+                            seen.insert(v, ());
+                            self.write_line(format!("(\"{}\", {}) => Some(\"{}\"),", group.name, e.value, e.ident));
+                        }
+                        _ => (),
+                    },
+                    None => (),
+                }
+            }
+        }
+        self.write_line("_ => None,");
+        self.decr_indent();
+        self.write_line("}");
+        self.decr_indent();
+        self.write_line("}");
+    }
+
+    fn write_error_fns(&mut self) {
+        static ERRORS: &'static [(&'static str, &'static str, &'static str)] = &[
+            ("InvalidEnum",                 "0x0500", "invalid enum"),
+            ("InvalidValue",                "0x0501", "invalid value"),
+            ("InvalidOperation",            "0x0502", "invalid operation"),
+            ("StackOverflow",               "0x0503", "stack overflow"),
+            ("StackUnderflow",              "0x0504", "stack underflow"),
+            ("OutOfMemory",                 "0x0505", "out of memory"),
+            ("InvalidFramebufferOperation", "0x0506", "invalid framebuffer operation"),
+            ("ContextLost",                 "0x0507", "context lost"),
+        ];
+
+        self.write_line("/// An error flag, as returned by `GetError`");
+        self.write_line("#[deriving(Clone, Eq)]");
+        self.write_line("pub enum GlError {");
+        self.incr_indent();
+        for &(variant, _, _) in ERRORS.iter() {
+            self.write_line(variant + ",");
+        }
+        self.write_line("Unknown(GLenum),");
+        self.decr_indent();
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("impl GlError {");
+        self.incr_indent();
+        self.write_line("/// Converts the value returned by `GetError`, returning `None` for `NO_ERROR`.");
+        self.write_line("pub fn from_glenum(value: GLenum) -> Option<GlError> {");
+        self.incr_indent();
+        self.write_line("match value {");
+        self.incr_indent();
+        self.write_line("0 => None,");
+        for &(variant, value, _) in ERRORS.iter() {
+            self.write_line(format!("{} => Some({}),", value, variant));
+        }
+        self.write_line("value => Some(Unknown(value)),");
+        self.decr_indent();
+        self.write_line("}");
+        self.decr_indent();
+        self.write_line("}");
+        self.decr_indent();
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("impl ToStr for GlError {");
+        self.incr_indent();
+        self.write_line("fn to_str(&self) -> ~str {");
+        self.incr_indent();
+        self.write_line("match *self {");
+        self.incr_indent();
+        for &(variant, _, desc) in ERRORS.iter() {
+            self.write_line(format!("{} => ~\"{}\",", variant, desc));
+        }
+        if self.options.enum_names {
+            self.write_line("Unknown(value) => match enum_name(value) {");
+            self.write_line("    Some(name) => format!(\"unknown error {} (0x{:x})\", name, value),");
+            self.write_line("    None => format!(\"unknown error (0x{:x})\", value),");
+            self.write_line("},");
+        } else {
+            self.write_line("Unknown(value) => format!(\"unknown error (0x{:x})\", value),");
+        }
+        self.decr_indent();
+        self.write_line("}");
+        self.decr_indent();
+        self.write_line("}");
+        self.decr_indent();
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("/// Returns the next error flag recorded by the context, resetting it to");
+        self.write_line("/// `NO_ERROR`.");
+        let error_fn = self.gen_error_fn_ident();
+        self.write_line(format!("pub fn {}() -> Result<(), GlError> \\{", error_fn));
+        self.write_line(format!("    match GlError::from_glenum({}()) \\{", self.gen_fn_ident("GetError")));
+        self.write_line("        Some(err) => Err(err),");
+        self.write_line("        None => Ok(()),");
+        self.write_line("    }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("/// Calls `GetError` until it returns `NO_ERROR`, returning every error flag");
+        self.write_line("/// that was set. Implementations may record several flags at once. To avoid");
+        self.write_line("/// spinning forever on a lost context, at most 32 flags are returned.");
+        self.write_line("pub fn drain_errors() -> ~[GlError] {");
+        self.write_line("    let mut errors = ~[];");
+        self.write_line("    while errors.len() < 32 {");
+        self.write_line(format!("        match {}() \\{", error_fn));
+        self.write_line("            Err(err) => errors.push(err),");
+        self.write_line("            Ok(()) => break,");
+        self.write_line("        }");
+        self.write_line("    }");
+        self.write_line("    errors");
+        self.write_line("}");
+    }
+
+    /// Writes a C-like enum for one of the debug output parameters, with
+    /// conversions to and from `GLenum`.
+    fn write_debug_enum(&mut self, name: &str, doc: &str, variants: &[(&str, &str)]) {
+        self.write_doc_comment(doc);
+        self.write_line("#[deriving(Clone, Eq)]");
+        self.write_line(format!("pub enum {} \\{", name));
+        self.incr_indent();
+        for &(variant, _) in variants.iter() {
+            self.write_line(variant + ",");
+        }
+        self.write_line(format!("Unknown{}(GLenum),", name));
+        self.decr_indent();
+        self.write_line("}");
+        self.write_line("");
+        self.write_line(format!("impl {} \\{", name));
+        self.incr_indent();
+        self.write_line(format!("pub fn from_glenum(value: GLenum) -> {} \\{", name));
+        self.incr_indent();
+        self.write_line("match value {");
+        self.incr_indent();
+        for &(variant, value) in variants.iter() {
+            self.write_line(format!("{} => {},", value, variant));
+        }
+        self.write_line(format!("value => Unknown{}(value),", name));
+        self.decr_indent();
+        self.write_line("}");
+        self.decr_indent();
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("pub fn to_glenum(&self) -> GLenum {");
+        self.incr_indent();
+        self.write_line("match *self {");
+        self.incr_indent();
+        for &(variant, value) in variants.iter() {
+            self.write_line(format!("{} => {},", variant, value));
+        }
+        self.write_line(format!("Unknown{}(value) => value,", name));
+        self.decr_indent();
+        self.write_line("}");
+        self.decr_indent();
+        self.write_line("}");
+        self.decr_indent();
+        self.write_line("}");
+    }
+
+    /// Writes an if-chain that calls the first loaded variant of a command,
+    /// trying the core, KHR and ARB versions in turn. The chain evaluates to
+    /// `false` if none of them are loaded.
+    fn write_debug_dispatch(&mut self, cmd: &str, args: &str) {
+        let variants = ["", "KHR", "ARB"].iter()
+            .map(|suffix| cmd + *suffix)
+            .filter(|ident| self.has_cmd(*ident))
+            .to_owned_vec();
+        if variants.is_empty() {
+            self.write_line("false");
+            return;
+        }
+        for (i, ident) in variants.iter().enumerate() {
+            self.write_line(format!("{}if ::{}::is_loaded() \\{",
+                if i == 0 { "" } else { "} else " }, self.gen_fn_ident(*ident)));
+            self.write_line(format!("    unsafe \\{ ::{}({}) \\}", self.gen_fn_ident(*ident), args));
+            self.write_line("    true");
+        }
+        self.write_line("} else {");
+        self.write_line("    false");
+        self.write_line("}");
+    }
+
+    fn write_debug_output_fns(&mut self) {
+        self.write_line("/// Safe registration of debug output callbacks, as provided by OpenGL 4.3,");
+        self.write_line("/// `GL_KHR_debug` and `GL_ARB_debug_output`. Messages are only generated once");
+        self.write_line("/// `DEBUG_OUTPUT` has been enabled, and may be delivered on another thread");
+        self.write_line("/// unless `DEBUG_OUTPUT_SYNCHRONOUS` is also enabled.");
+        self.write_line("pub mod debug {");
+        self.incr_indent();
+        self.write_line("use std::cast;");
+        self.write_line("use std::libc::*;");
+        self.write_line("use std::ptr;");
+        self.write_line("use std::str;");
+        self.write_line("use super::types::*;");
+        self.write_line("");
+        self.write_debug_enum("Source", "The source of a debug message", [
+            ("ApiSource", "0x8246"),
+            ("WindowSystemSource", "0x8247"),
+            ("ShaderCompilerSource", "0x8248"),
+            ("ThirdPartySource", "0x8249"),
+            ("ApplicationSource", "0x824A"),
+            ("OtherSource", "0x824B")
+        ]);
+        self.write_line("");
+        self.write_debug_enum("Type", "The type of a debug message", [
+            ("ErrorType", "0x824C"),
+            ("DeprecatedBehaviorType", "0x824D"),
+            ("UndefinedBehaviorType", "0x824E"),
+            ("PortabilityType", "0x824F"),
+            ("PerformanceType", "0x8250"),
+            ("OtherType", "0x8251"),
+            ("MarkerType", "0x8268"),
+            ("PushGroupType", "0x8269"),
+            ("PopGroupType", "0x826A")
+        ]);
+        self.write_line("");
+        self.write_debug_enum("Severity", "The severity of a debug message", [
+            ("HighSeverity", "0x9146"),
+            ("MediumSeverity", "0x9147"),
+            ("LowSeverity", "0x9148"),
+            ("NotificationSeverity", "0x826B")
+        ]);
+        self.write_line("");
+        self.write_line("/// A function that receives decoded debug messages");
+        self.write_line("pub type Callback = ~fn(source: Source, ty: Type, id: GLuint, severity: Severity, message: &str);");
+        self.write_line("");
+        self.write_line("// The boxed callback that is currently registered as the `userParam`");
+        self.write_line("static mut CALLBACK: *c_void = 0 as *c_void;");
+        self.write_line("");
+        self.write_line("extern \"C\" fn handle_message(source: GLenum, gltype: GLenum, id: GLuint, severity: GLenum, length: GLsizei, message: *GLchar, userParam: *c_void) {");
+        self.write_line("    unsafe {");
+        self.write_line("        let callback: &Callback = cast::transmute(userParam);");
+        self.write_line("        let message = str::raw::from_buf_len(message as *u8, length as uint);");
+        self.write_line("        (*callback)(Source::from_glenum(source), Type::from_glenum(gltype), id,");
+        self.write_line("                    Severity::from_glenum(severity), message);");
+        self.write_line("    }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("fn register(callback: GLDEBUGPROC, userParam: *c_void) -> bool {");
+        self.incr_indent();
+        self.write_debug_dispatch("DebugMessageCallback", "callback, userParam");
+        self.decr_indent();
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("/// Registers a closure to receive debug messages, replacing any previously");
+        self.write_line("/// registered callback. Returns `false` if none of the `DebugMessageCallback`");
+        self.write_line("/// variants have been loaded.");
+        self.write_line("pub fn set_callback(callback: Callback) -> bool {");
+        self.write_line("    unset_callback();");
+        self.write_line("    unsafe {");
+        self.write_line("        let boxed: ~Callback = ~callback;");
+        self.write_line("        let userParam: *c_void = cast::transmute(boxed);");
+        self.write_line("        if register(handle_message, userParam) {");
+        self.write_line("            CALLBACK = userParam;");
+        self.write_line("            true");
+        self.write_line("        } else {");
+        self.write_line("            let _: ~Callback = cast::transmute(userParam);");
+        self.write_line("            false");
+        self.write_line("        }");
+        self.write_line("    }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("/// Unregisters the current callback, if any, and frees it.");
+        self.write_line("pub fn unset_callback() {");
+        self.write_line("    unsafe {");
+        self.write_line("        if !CALLBACK.is_null() {");
+        self.write_line("            register(cast::transmute(0u), ptr::null());");
+        self.write_line("            let _: ~Callback = cast::transmute(CALLBACK);");
+        self.write_line("            CALLBACK = ptr::null();");
+        self.write_line("        }");
+        self.write_line("    }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("/// Enables or disables the messages matching the supplied filters, where");
+        self.write_line("/// `None` matches any value. Returns `false` if none of the");
+        self.write_line("/// `DebugMessageControl` variants have been loaded.");
+        self.write_line("pub fn control(source: Option<Source>, ty: Option<Type>, severity: Option<Severity>, enabled: bool) -> bool {");
+        self.incr_indent();
+        self.write_line("static DONT_CARE: GLenum = 0x1100;");
+        self.write_line("let source = source.map_default(DONT_CARE, |s| s.to_glenum());");
+        self.write_line("let ty = ty.map_default(DONT_CARE, |t| t.to_glenum());");
+        self.write_line("let severity = severity.map_default(DONT_CARE, |s| s.to_glenum());");
+        self.write_line(format!("let enabled = if enabled \\{ ::{} \\} else \\{ ::{} \\};",
+                                self.gen_enum_ident("TRUE"), self.gen_enum_ident("FALSE")));
+        self.write_debug_dispatch("DebugMessageControl", "source, ty, severity, 0, ptr::null(), enabled");
+        self.decr_indent();
+        self.write_line("}");
+        self.decr_indent();
+        self.write_line("}");
+    }
+
+    /// Generates an expression that formats a parameter for the mock call log
+    fn gen_mock_arg(binding: &Binding) -> ~str {
+        let ident = Generator::gen_binding_ident(binding, true);
+        match ty::to_rust_ty(binding.ty) {
+            "GLDEBUGPROC" | "GLDEBUGPROCAMD" | "GLDEBUGPROCARB" | "GLDEBUGPROCKHR" => ~"~\"<callback>\"",
+            "GLsync" | "GLeglImageOES" => format!("format!(\"\\{:p\\}\", {})", ident),
+            ty if ty.starts_with("*") => format!("format!(\"\\{:p\\}\", {})", ident),
+            _ => format!("{}.to_str()", ident),
+        }
+    }
+
+    /// Generates the body of a mock function, after the call has been recorded
+    fn gen_mock_body(&self, cmd: &Cmd) -> ~str {
+        if self.options.emulate && emulate::CMDS.iter().any(|&c| c == cmd.proto.ident) {
+            return format!("{}super::emulate::{}({}){}",
+                if cmd.is_safe { "" } else { "unsafe { " },
+                cmd.proto.ident,
+                Generator::gen_param_ident_list(cmd),
+                if cmd.is_safe { "" } else { " }" });
+        }
+        let ret_ty = ty::to_rust_ty(cmd.proto.ty).replace("*mut ", "*");
+        let is_gen = cmd.proto.ident.starts_with("Gen") && cmd.params.len() == 2
+            && cmd.params[1].ty.as_slice() == "GLuint *";
+        if is_gen {
+            let n = Generator::gen_binding_ident(&cmd.params[0], true);
+            let names = Generator::gen_binding_ident(&cmd.params[1], true);
+            format!("for i in range(0, {} as int) \\{ unsafe \\{ *ptr::mut_offset({}, i) = next_name(); \\} \\}", n, names)
+        } else if ret_ty.as_slice() == "c_void" || ret_ty.as_slice() == "GLvoid" {
+            ~""
+        } else if cmd.proto.ident.starts_with("Create") && ret_ty.as_slice() == "GLuint" {
+            ~"next_name()"
+        } else if ret_ty.starts_with("*") || ret_ty.as_slice() == "GLsync" {
+            format!("return_value(\"{}\") as uint as {}", cmd.proto.ident, ret_ty)
+        } else {
+            format!("return_value(\"{}\") as {}", cmd.proto.ident, ret_ty)
+        }
+    }
+
+    fn write_mock_fns(&mut self) {
+        self.write_line("/// A mock implementation that records each call instead of passing it to a");
+        self.write_line("/// driver, allowing rendering code to be tested without a context. The log is");
+        self.write_line("/// stored in task-local storage, so tests running in parallel do not interfere.");
+        self.write_line("///");
+        self.write_line("/// ~~~");
+        self.write_line("/// gl::load_with(gl::mock::get_proc_address);");
+        self.write_line("/// draw_triangle();");
+        self.write_line("/// let calls = gl::mock::take_calls().map(|c| c.to_str());");
+        self.write_line("/// assert_eq!(calls, ~[~\"BindBuffer(34962, 3)\", ~\"DrawArrays(4, 0, 3)\"]);");
+        self.write_line("/// ~~~");
+        self.write_line("pub mod mock {");
+        self.incr_indent();
+        self.write_line("use std::cast;");
+        self.write_line("use std::libc::*;");
+        self.write_line("use std::local_data;");
+        self.write_line("use std::ptr;");
+        self.write_line("use super::types::*;");
+        self.write_line("");
+        self.write_line("/// A call recorded by the mock implementation");
+        self.write_line("#[deriving(Clone, Eq)]");
+        self.write_line("pub struct Call {");
+        self.write_line("    name: &'static str,");
+        self.write_line("    args: ~[~str],");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("impl ToStr for Call {");
+        self.write_line("    fn to_str(&self) -> ~str {");
+        self.write_line("        format!(\"{}({})\", self.name, self.args.connect(\", \"))");
+        self.write_line("    }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("local_data_key!(calls_key: ~[Call])");
+        self.write_line("local_data_key!(returns_key: ~[(&'static str, u64)])");
+        self.write_line("local_data_key!(next_name_key: GLuint)");
+        self.write_line("");
+        self.write_line("fn record(name: &'static str, args: ~[~str]) {");
+        self.write_line("    do local_data::modify(calls_key) |calls| {");
+        self.write_line("        let mut calls = calls.unwrap_or(~[]);");
+        self.write_line("        calls.push(Call { name: name, args: args.clone() });");
+        self.write_line("        Some(calls)");
+        self.write_line("    }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("fn return_value(name: &'static str) -> u64 {");
+        self.write_line("    do local_data::get(returns_key) |returns| {");
+        self.write_line("        returns.and_then(|rs| rs.iter().find(|&&(n, _)| n == name).map(|&(_, v)| v))");
+        self.write_line("               .unwrap_or(0)");
+        self.write_line("    }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("fn next_name() -> GLuint {");
+        self.write_line("    let name = local_data::pop(next_name_key).unwrap_or(0) + 1;");
+        self.write_line("    local_data::set(next_name_key, name);");
+        self.write_line("    name");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("/// Returns the calls recorded so far");
+        self.write_line("pub fn calls() -> ~[Call] {");
+        self.write_line("    local_data::get(calls_key, |calls| calls.map_default(~[], |c| c.clone()))");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("/// Returns the calls recorded so far, clearing the log");
+        self.write_line("pub fn take_calls() -> ~[Call] {");
+        self.write_line("    local_data::pop(calls_key).unwrap_or(~[])");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("/// Sets the value returned by the named function, which otherwise returns");
+        self.write_line("/// zero. Pointers are returned by converting the value to an address.");
+        self.write_line("/// `Gen*` and `Create*` functions return names from an increasing counter.");
+        self.write_line("pub fn set_return(name: &'static str, value: u64) {");
+        self.write_line("    do local_data::modify(returns_key) |returns| {");
+        self.write_line("        let mut returns = returns.unwrap_or(~[]);");
+        self.write_line("        returns.retain(|&(n, _)| n != name);");
+        self.write_line("        returns.push((name, value));");
+        self.write_line("        Some(returns)");
+        self.write_line("    }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("/// Clears the call log, the configured return values and the name counter");
+        self.write_line("pub fn reset() {");
+        self.write_line("    local_data::pop(calls_key);");
+        self.write_line("    local_data::pop(returns_key);");
+        self.write_line("    local_data::pop(next_name_key);");
+        self.write_line("}");
+        self.write_line("");
+        self.for_cmds(|c| {
+            let args = c.params.iter()
+                .map(|b| Generator::gen_mock_arg(b))
+                .to_owned_vec()
+                .connect(", ");
+            self.write_line(format!("pub extern \"C\" fn {}({}){} \\{",
+                c.proto.ident,
+                Generator::gen_param_list(c, true),
+                Generator::gen_return_suffix(c)));
+            self.write_line(format!("    record(\"{}\", ~[{}]);", c.proto.ident, args));
+            let body = self.gen_mock_body(c);
+            if !body.is_empty() {
+                self.write_line("    " + body);
+            }
+            self.write_line("}");
+        });
+        self.write_line("");
+        self.write_line("/// A load function that returns the mock implementation of each symbol, for");
+        self.write_line("/// use with `load_with`.");
+        self.write_line("pub fn get_proc_address(symbol: &str) -> Option<extern \"C\" fn()> {");
+        self.incr_indent();
+        self.write_line("unsafe {");
+        self.incr_indent();
+        self.write_line("match symbol {");
+        self.incr_indent();
+        self.for_cmds(
+            |c| self.write_line(format!("\"{}\" => Some(cast::transmute({})),",
+                                        Generator::gen_symbol_name(&self.ns, c), c.proto.ident))
+        );
+        self.write_line("_ => None,");
+        self.decr_indent();
+        self.write_line("}");
+        self.decr_indent();
+        self.write_line("}");
+        self.decr_indent();
+        self.write_line("}");
+        self.decr_indent();
+        self.write_line("}");
+    }
+
+    fn write_emulate_fns(&mut self) {
+        self.write_line("/// An emulation of the object model behind the mock implementation. Buffers,");
+        self.write_line("/// textures, vertex arrays, shaders, programs and framebuffers are tracked");
+        self.write_line("/// per task, and invalid usage raises the error required by the specification,");
+        self.write_line("/// which can be retrieved with `GetError`.");
+        self.write_line("pub mod emulate {");
+        self.incr_indent();
+        for line in emulate::SRC.iter() {
+            self.write_line(*line);
+        }
+        self.decr_indent();
+        self.write_line("}");
+    }
+
+    /// Returns the expression for the number of elements of a pointer
+    /// parameter, if it is a literal, the name of another parameter, or a
+    /// parameter multiplied by a literal, such as `count*4`.
+    fn gen_capture_len(cmd: &Cmd, binding: &Binding) -> Option<~str> {
+        let param = |ident: &str| cmd.params.iter()
+            .find(|p| p.ident.as_slice() == ident)
+            .map(|p| Generator::gen_binding_ident(p, true));
+        let is_literal = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_digit());
+        match binding.len {
+            Some(ref len) if is_literal(*len) => Some(len.clone()),
+            Some(ref len) => match len.find('*') {
+                Some(i) if is_literal(len.slice_from(i + 1)) => {
+                    param(len.slice_to(i)).map(|p| format!("({} as uint * {})", p, len.slice_from(i + 1)))
+                }
+                Some(_) => None,
+                None => param(*len),
+            },
+            None => None,
+        }
+    }
+
+    /// Works out how a parameter is written to a capture stream, returning
+    /// `None` for parameters that cannot be replayed, such as callbacks,
+    /// handles, and pointers to data whose length cannot be worked out.
+    /// Addresses from the capturing process are never replayed.
+    fn gen_capture_arg(cmd: &Cmd, binding: &Binding) -> Option<CaptureArg> {
+        let len = Generator::gen_capture_len(cmd, binding);
+        let lengths = cmd.params.iter()
+            .find(|p| p.ident.as_slice() == "length" && ty::to_rust_ty(p.ty) == "*GLint")
+            .map(|p| Generator::gen_binding_ident(p, true));
+        match ty::to_rust_ty(binding.ty) {
+            "GLDEBUGPROC" | "GLDEBUGPROCAMD" | "GLDEBUGPROCARB" | "GLDEBUGPROCKHR" => None,
+            "GLenum" | "GLbitfield" | "GLuint" => Some(CaptureScalar("le_u32", "u32")),
+            "GLint" | "GLsizei" | "GLfixed" | "GLclampx" => Some(CaptureScalar("le_i32", "i32")),
+            "GLshort" => Some(CaptureScalar("le_i16", "i16")),
+            "GLushort" | "GLhalf" | "GLhalfARB" | "GLhalfNV" => Some(CaptureScalar("le_u16", "u16")),
+            "GLbyte" => Some(CaptureScalar("i8", "i8")),
+            "GLubyte" | "GLboolean" | "GLchar" => Some(CaptureScalar("u8", "u8")),
+            "GLfloat" | "GLclampf" => Some(CaptureScalar("le_f32", "f32")),
+            "GLdouble" | "GLclampd" => Some(CaptureScalar("le_f64", "f64")),
+            "GLint64" | "GLint64EXT" | "GLintptr" | "GLsizeiptr" |
+            "GLintptrARB" | "GLsizeiptrARB" => Some(CaptureScalar("le_i64", "i64")),
+            "GLuint64" | "GLuint64EXT" => Some(CaptureScalar("le_u64", "u64")),
+            "**GLchar" | "**GLcharARB" if len.is_some() => Some(CaptureCStrs(len.unwrap(), lengths)),
+            "*GLchar" | "*GLcharARB" if binding.len.is_none() => Some(CaptureCStr),
+            // outputs with an unknown length are written to a scratch buffer
+            ty if ty.starts_with("*mut ") && !ty.starts_with("*mut *") => {
+                match binding.len {
+                    Some(_) if len.is_none() => None,
+                    _ => Some(CaptureOutput(ty.slice_from(5).to_owned(), len)),
+                }
+            }
+            ty if ty.starts_with("*") && !ty.starts_with("**") && !ty.starts_with("*mut ") && len.is_some() => {
+                Some(CaptureBytes(ty.slice_from(1).to_owned(), len.unwrap()))
+            }
+            _ => None,
+        }
+    }
+
+    fn gen_capture_args(cmd: &Cmd) -> Option<~[CaptureArg]> {
+        let mut args = ~[];
+        for binding in cmd.params.iter() {
+            match Generator::gen_capture_arg(cmd, binding) {
+                Some(arg) => args.push(arg),
+                None => return None,
+            }
+        }
+        Some(args)
+    }
+
+    fn gen_capture_elem_size(elem: &str) -> ~str {
+        match elem {
+            "c_void" | "GLvoid" => ~"1",
+            elem => format!("sys::size_of::<{}>()", elem),
+        }
+    }
+
+    /// Writes the function that records a call before making it. Commands
+    /// whose arguments cannot be recorded write a skipped record instead.
+    fn write_capture_stub(&self, id: uint, cmd: &Cmd, args: &Option<~[CaptureArg]>) {
+        self.write_line(format!("pub extern \"C\" fn {}({}){} \\{",
+            cmd.proto.ident,
+            Generator::gen_param_list(cmd, true),
+            Generator::gen_return_suffix(cmd)));
+        self.write_line("    unsafe {");
+        self.write_line("        match local_data::get(writer_key, |w| w.map(|&w| w)) {");
+        self.write_line("            Some(capture_writer) => {");
+        let args = match *args {
+            Some(ref args) => {
+                self.write_line(format!("                capture_writer.write_le_u16({});", id));
+                args.as_slice()
+            }
+            None => {
+                self.write_line("                capture_writer.write_le_u16(SKIPPED);");
+                self.write_line(format!("                capture_writer.write_le_u16({});", id));
+                &[]
+            }
+        };
+        for (binding, arg) in cmd.params.iter().zip(args.iter()) {
+            let ident = Generator::gen_binding_ident(binding, true);
+            let line = match *arg {
+                CaptureScalar(suffix, wire) => {
+                    format!("capture_writer.write_{}({} as {});", suffix, ident, wire)
+                }
+                CaptureBytes(ref elem, ref len) => {
+                    format!("write_bytes(capture_writer, {} as *u8, ({} as uint) * {});",
+                            ident, *len, Generator::gen_capture_elem_size(*elem))
+                }
+                CaptureCStr => format!("write_c_str(capture_writer, {} as *c_char);", ident),
+                CaptureCStrs(ref len, ref lengths) => {
+                    format!("write_c_strs(capture_writer, {} as **c_char, {}, {} as uint);", ident,
+                            lengths.as_ref().map_default(~"ptr::null()", |l| l.clone()), *len)
+                }
+                CaptureOutput(*) => format!("// {} is written to by the command", ident),
+            };
+            self.write_line("                " + line);
+        }
+        self.write_line("            }");
+        self.write_line("            None => (),");
+        self.write_line("        }");
+        self.write_line(format!("        let real_fn: extern \"C\" fn({}){} = cast::transmute(REAL[{}]);",
+            Generator::gen_param_ty_list(cmd),
+            Generator::gen_return_suffix(cmd),
+            id));
+        self.write_line(format!("        real_fn({})", Generator::gen_param_ident_list(cmd)));
+        self.write_line("    }");
+        self.write_line("}");
+    }
+
+    fn write_replay_arm(&self, id: uint, cmd: &Cmd, args: &[CaptureArg]) {
+        self.write_line(format!("{} => \\{", id));
+        for (binding, arg) in cmd.params.iter().zip(args.iter()) {
+            let ident = Generator::gen_binding_ident(binding, true);
+            let ty = ty::to_rust_ty(binding.ty);
+            match *arg {
+                CaptureScalar(suffix, _) => {
+                    self.write_line(format!("    let {} = replay_reader.read_{}() as {};", ident, suffix, ty));
+                }
+                CaptureBytes(*) | CaptureCStr => {
+                    self.write_line(format!("    let {}_data = read_bytes(replay_reader);", ident));
+                    self.write_line(format!("    let {} = data_ptr(&{}_data) as {};", ident, ident, ty));
+                }
+                CaptureCStrs(*) => {
+                    self.write_line(format!("    let {}_data = read_c_strs(replay_reader);", ident));
+                    self.write_line(format!("    let {}_ptrs = {}_data.map(|s| vec::raw::to_ptr(*s) as *c_char);", ident, ident));
+                    self.write_line(format!("    let {} = vec::raw::to_ptr({}_ptrs) as {};", ident, ident, ty));
+                }
+                CaptureOutput(*) => (),
+            }
+        }
+        // outputs are allocated once the parameters giving their lengths are known
+        for (binding, arg) in cmd.params.iter().zip(args.iter()) {
+            match *arg {
+                CaptureOutput(ref elem, ref len) => {
+                    let ident = Generator::gen_binding_ident(binding, true);
+                    let size = match *len {
+                        Some(ref len) => format!("({} as uint) * {}", *len, Generator::gen_capture_elem_size(*elem)),
+                        None => ~"SCRATCH_SIZE",
+                    };
+                    self.write_line(format!("    let mut {}_data = vec::from_elem({}, 0u8);", ident, size));
+                    self.write_line(format!("    let {} = vec::raw::to_mut_ptr({}_data) as {};",
+                                            ident, ident, ty::to_rust_ty(binding.ty)));
+                }
+                _ => (),
+            }
+        }
+        self.write_line(format!("    ::{}({});", self.gen_cmd_ident(cmd), Generator::gen_param_ident_list(cmd)));
+        self.write_line("}");
+    }
+
+    fn write_capture_fns(&mut self) {
+        let mut count = 0u;
+        self.for_cmds(|_| count += 1);
+
+        self.write_line("/// Capture of the calls made through the generated functions into a compact");
+        self.write_line("/// binary stream, and replay of captured streams. Numbers are written as");
+        self.write_line("/// little-endian values, along with strings and any array whose length is");
+        self.write_line("/// given by another parameter, such as the data passed to `BufferData`.");
+        self.write_line("/// The arguments of commands taking other pointers or handles, such as");
+        self.write_line("/// `DrawElements`, `VertexAttribPointer`, `TexImage2D` and `ClientWaitSync`, are not");
+        self.write_line("/// recorded, since addresses from the capturing process would be meaningless");
+        self.write_line("/// on replay. Calls to them are written as skipped records, which `replay`");
+        self.write_line("/// reports instead of making, and they are listed in `UNRECORDED`. Object");
+        self.write_line("/// names are not remapped on replay, which relies on a fresh context");
+        self.write_line("/// generating the same names again.");
+        self.write_line("///");
+        self.write_line("/// ~~~");
+        self.write_line("/// gl::capture::load_with(glfw::get_proc_address);");
+        self.write_line("/// gl::capture::start(io::file_writer(&Path::new(\"frame.glcap\"), [io::Create]).unwrap());");
+        self.write_line("/// draw_frame();");
+        self.write_line("/// gl::capture::stop();");
+        self.write_line("/// ~~~");
+        self.write_line("pub mod capture {");
+        self.incr_indent();
+        self.write_line("use std::cast;");
+        self.write_line("use std::io::{Reader, ReaderUtil, Writer, WriterUtil};");
+        self.write_line("use std::libc::*;");
+        self.write_line("use std::local_data;");
+        self.write_line("use std::ptr;");
+        self.write_line("use std::str;");
+        self.write_line("use std::sys;");
+        self.write_line("use std::vec;");
+        self.write_line("use super::types::*;");
+        self.write_line("");
+        self.write_line("static MAGIC: &'static [u8] = bytes!(\"GLRSCAP1\");");
+        self.write_line("");
+        self.write_line("// Bytes allocated on replay for outputs of unknown length");
+        self.write_line("static SCRATCH_SIZE: uint = 4096;");
+        self.write_line("");
+        self.write_line("// Written in place of a command id for a call whose arguments were not");
+        self.write_line("// recorded, followed by the id");
+        self.write_line("static SKIPPED: u16 = 0xFFFF;");
+        self.write_line("");
+        self.write_line("// The symbols of the commands, indexed by id");
+        self.write_line("static NAMES: &'static [&'static str] = &[");
+        self.for_cmds(|c| self.write_line(format!("    \"{}\",", Generator::gen_symbol_name(&self.ns, c))));
+        self.write_line("];");
+        self.write_line("");
+        self.write_line("/// The commands whose arguments are not recorded, so that calls to them are");
+        self.write_line("/// skipped on replay");
+        self.write_line("pub static UNRECORDED: &'static [&'static str] = &[");
+        self.for_cmds(|c| {
+            if Generator::gen_capture_args(c).is_none() {
+                self.write_line(format!("    \"{}\",", Generator::gen_symbol_name(&self.ns, c)));
+            }
+        });
+        self.write_line("];");
+        self.write_line("");
+        self.write_line("// The loaded functions that calls are forwarded to, indexed by id");
+        self.write_line(format!("static mut REAL: [*c_void, ..{}] = [0 as *c_void, ..{}];", count, count));
+        self.write_line("");
+        self.write_line("local_data_key!(writer_key: @Writer)");
+        self.write_line("");
+        self.write_line("/// Loads the function pointers with `::load_with`, routing each command");
+        self.write_line("/// through a function that records it while capturing.");
+        self.write_line("pub fn load_with(loadfn: &fn(symbol: &str) -> Option<extern \"C\" fn()>) -> ::LoadReport {");
+        self.write_line("    do ::load_with |symbol| {");
+        self.write_line("        match (NAMES.iter().position(|&name| name == symbol), loadfn(symbol)) {");
+        self.write_line("            (Some(id), Some(real_fn)) => unsafe {");
+        self.write_line("                REAL[id] = cast::transmute(real_fn);");
+        self.write_line("                Some(wrapper(id))");
+        self.write_line("            },");
+        self.write_line("            (_, real_fn) => real_fn,");
+        self.write_line("        }");
+        self.write_line("    }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("/// Starts writing the calls made on the current task to `writer`");
+        self.write_line("pub fn start(writer: @Writer) {");
+        self.write_line("    writer.write(MAGIC);");
+        self.write_line("    writer.write_le_u32(NAMES.len() as u32);");
+        self.write_line("    for name in NAMES.iter() {");
+        self.write_line("        writer.write_u8(name.len() as u8);");
+        self.write_line("        writer.write(name.as_bytes());");
+        self.write_line("    }");
+        self.write_line("    local_data::set(writer_key, writer);");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("/// Stops capturing the calls made on the current task");
+        self.write_line("pub fn stop() {");
+        self.write_line("    match local_data::pop(writer_key) {");
+        self.write_line("        Some(writer) => writer.flush(),");
+        self.write_line("        None => (),");
+        self.write_line("    }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("/// Makes each call in a captured stream through the loaded functions,");
+        self.write_line("/// returning the commands of the skipped calls, in the order they were made.");
+        self.write_line("pub fn replay(reader: @Reader) -> ~[&'static str] {");
+        self.write_line("    if reader.read_bytes(MAGIC.len()).as_slice() != MAGIC {");
+        self.write_line("        fail!(\"Not a capture stream\");");
+        self.write_line("    }");
+        self.write_line("    // map the ids in the stream to the ids in these bindings");
+        self.write_line("    let ids = do vec::from_fn(reader.read_le_u32() as uint) |_| {");
+        self.write_line("        let len = reader.read_u8() as uint;");
+        self.write_line("        let name = str::from_utf8(reader.read_bytes(len));");
+        self.write_line("        match NAMES.iter().position(|&n| n == name) {");
+        self.write_line("            Some(id) => id,");
+        self.write_line("            None => fail!(\"{} is not present in these bindings\", name),");
+        self.write_line("        }");
+        self.write_line("    };");
+        self.write_line("    let mut skipped = ~[];");
+        self.write_line("    while !reader.eof() {");
+        self.write_line("        match reader.read_le_u16() {");
+        self.write_line("            SKIPPED => skipped.push(NAMES[ids[reader.read_le_u16() as uint]]),");
+        self.write_line("            id => unsafe { replay_call(ids[id as uint], reader) },");
+        self.write_line("        }");
+        self.write_line("    }");
+        self.write_line("    skipped");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("// Arrays are prefixed with their length in bytes, or 0xFFFFFFFF if null");
+        self.write_line("fn write_bytes(writer: @Writer, data: *u8, len: uint) {");
+        self.write_line("    if data.is_null() {");
+        self.write_line("        writer.write_le_u32(0xFFFFFFFF);");
+        self.write_line("    } else {");
+        self.write_line("        writer.write_le_u32(len as u32);");
+        self.write_line("        unsafe { vec::raw::buf_as_slice(data, len, |bytes| writer.write(bytes)) }");
+        self.write_line("    }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("// Strings include their terminator, so they can be replayed from the buffer");
+        self.write_line("unsafe fn write_c_str(writer: @Writer, s: *c_char) {");
+        self.write_line("    let len = if s.is_null() { 0 } else { str::raw::from_c_str(s).len() + 1 };");
+        self.write_line("    write_bytes(writer, s as *u8, len);");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("// Strings with a non-negative length need not be terminated, so only that many");
+        self.write_line("// bytes are read, and a terminator is added");
+        self.write_line("unsafe fn write_c_strs(writer: @Writer, strs: **c_char, lengths: *GLint, count: uint) {");
+        self.write_line("    writer.write_le_u32(count as u32);");
+        self.write_line("    for i in range(0, count as int) {");
+        self.write_line("        let s = *ptr::offset(strs, i);");
+        self.write_line("        let len = if lengths.is_null() { -1 } else { *ptr::offset(lengths, i) };");
+        self.write_line("        if s.is_null() || len < 0 {");
+        self.write_line("            write_c_str(writer, s);");
+        self.write_line("        } else {");
+        self.write_line("            writer.write_le_u32(len as u32 + 1);");
+        self.write_line("            vec::raw::buf_as_slice(s as *u8, len as uint, |bytes| writer.write(bytes));");
+        self.write_line("            writer.write_u8(0);");
+        self.write_line("        }");
+        self.write_line("    }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("fn read_bytes(reader: @Reader) -> Option<~[u8]> {");
+        self.write_line("    match reader.read_le_u32() {");
+        self.write_line("        0xFFFFFFFF => None,");
+        self.write_line("        len => Some(reader.read_bytes(len as uint)),");
+        self.write_line("    }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("fn read_c_strs(reader: @Reader) -> ~[~[u8]] {");
+        self.write_line("    let count = reader.read_le_u32() as uint;");
+        self.write_line("    vec::from_fn(count, |_| read_bytes(reader).unwrap_or(~[0u8]))");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("fn data_ptr(data: &Option<~[u8]>) -> *u8 {");
+        self.write_line("    match *data {");
+        self.write_line("        Some(ref bytes) => vec::raw::to_ptr(*bytes),");
+        self.write_line("        None => ptr::null(),");
+        self.write_line("    }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("fn wrapper(id: uint) -> extern \"C\" fn() {");
+        self.incr_indent();
+        self.write_line("unsafe {");
+        self.incr_indent();
+        self.write_line("match id {");
+        self.incr_indent();
+        let mut id = 0;
+        do self.for_cmds |c| {
+            self.write_line(format!("{} => cast::transmute({}),", id, c.proto.ident));
+            id += 1;
+        }
+        self.write_line("_ => fail!(\"Invalid command id {}\", id),");
+        self.decr_indent();
+        self.write_line("}");
+        self.decr_indent();
+        self.write_line("}");
+        self.decr_indent();
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("unsafe fn replay_call(id: uint, replay_reader: @Reader) {");
+        self.incr_indent();
+        self.write_line("match id {");
+        self.incr_indent();
+        let mut id = 0;
+        do self.for_cmds |c| {
+            match Generator::gen_capture_args(c) {
+                Some(args) => self.write_replay_arm(id, c, args),
+                None => debug2!("Not recording {}", c.proto.ident),
+            }
+            id += 1;
+        }
+        self.write_line("_ => fail!(\"Invalid command id {}\", id),");
+        self.decr_indent();
+        self.write_line("}");
+        self.decr_indent();
+        self.write_line("}");
+        self.write_line("");
+        let mut id = 0;
+        do self.for_cmds |c| {
+            self.write_capture_stub(id, c, &Generator::gen_capture_args(c));
+            id += 1;
+        }
+        self.decr_indent();
+        self.write_line("}");
+    }
+
+    fn write_stats_fns(&mut self) {
+        let mut count = 0u;
+        self.for_cmds(|_| count += 1);
+
+        self.write_line("/// Counts of the calls made to each command, for seeing how many draw calls");
+        self.write_line("/// and state changes a frame makes without an external profiler. The time");
+        self.write_line("/// spent in each command is also accumulated if timing is enabled. The");
+        self.write_line("/// counters are not synchronised, so calls should be made from one task.");
+        self.write_line("///");
+        self.write_line("/// ~~~");
+        self.write_line("/// draw_frame();");
+        self.write_line("/// for s in gl::stats::take().iter() {");
+        self.write_line("///     println(s.to_str());");
+        self.write_line("/// }");
+        self.write_line("/// ~~~");
+        self.write_line("pub mod stats {");
+        self.incr_indent();
+        self.write_line("use extra::sort;");
+        self.write_line("use extra::time;");
+        self.write_line("");
+        self.write_line("/// The calls made to a command since the counters were last reset");
+        self.write_line("#[deriving(Clone, Eq)]");
+        self.write_line("pub struct CommandStats {");
+        self.write_line("    name: &'static str,");
+        self.write_line("    calls: u64,");
+        self.write_line("    /// The time spent in the command in nanoseconds, if timing was enabled");
+        self.write_line("    time_ns: u64,");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("impl ToStr for CommandStats {");
+        self.write_line("    fn to_str(&self) -> ~str {");
+        self.write_line("        format!(\"{}: {} calls, {} ns\", self.name, self.calls, self.time_ns)");
+        self.write_line("    }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("static NAMES: &'static [&'static str] = &[");
+        self.for_cmds(|c| self.write_line(format!("    \"{}\",", c.proto.ident)));
+        self.write_line("];");
+        self.write_line("");
+        self.write_line(format!("static mut CALLS: [u64, ..{}] = [0, ..{}];", count, count));
+        self.write_line(format!("static mut TIME_NS: [u64, ..{}] = [0, ..{}];", count, count));
+        self.write_line("static mut TIMING: bool = false;");
+        self.write_line("");
+        self.write_line("/// Enables or disables timing of each call, which is disabled by default");
+        self.write_line("pub fn set_timing(enabled: bool) {");
+        self.write_line("    unsafe { TIMING = enabled }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("#[doc(hidden)]");
+        self.write_line("#[inline]");
+        self.write_line("pub fn start() -> u64 {");
+        self.write_line("    unsafe { if TIMING { time::precise_time_ns() } else { 0 } }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("#[doc(hidden)]");
+        self.write_line("#[inline]");
+        self.write_line("pub fn record(id: uint, start: u64) {");
+        self.write_line("    unsafe {");
+        self.write_line("        CALLS[id] += 1;");
+        self.write_line("        if start != 0 {");
+        self.write_line("            TIME_NS[id] += time::precise_time_ns() - start;");
+        self.write_line("        }");
+        self.write_line("    }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("/// Returns the commands called since the counters were last reset, with the");
+        self.write_line("/// most called first");
+        self.write_line("pub fn snapshot() -> ~[CommandStats] {");
+        self.write_line("    let mut stats = ~[];");
+        self.write_line("    for (id, &name) in NAMES.iter().enumerate() {");
+        self.write_line("        let (calls, time_ns) = unsafe { (CALLS[id], TIME_NS[id]) };");
+        self.write_line("        if calls > 0 {");
+        self.write_line("            stats.push(CommandStats { name: name, calls: calls, time_ns: time_ns });");
+        self.write_line("        }");
+        self.write_line("    }");
+        self.write_line("    sort::quick_sort(stats, |a, b| a.calls >= b.calls);");
+        self.write_line("    stats");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("/// Sets every counter back to zero");
+        self.write_line("pub fn reset() {");
+        self.write_line("    unsafe {");
+        self.write_line("        for n in CALLS.mut_iter() { *n = 0 }");
+        self.write_line("        for n in TIME_NS.mut_iter() { *n = 0 }");
+        self.write_line("    }");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("/// Returns a snapshot and resets the counters, for sampling once per frame");
+        self.write_line("pub fn take() -> ~[CommandStats] {");
+        self.write_line("    let stats = snapshot();");
+        self.write_line("    reset();");
+        self.write_line("    stats");
+        self.write_line("}");
+        self.decr_indent();
+        self.write_line("}");
+    }
+
+    /// Writes the items of a source file of the generator, skipping its
+    /// licence header and stopping before its tests
+    fn write_src(&self, src: &str) {
+        let lines = src.line_iter()
+            .skip_while(|l| l.starts_with("//") || l.is_empty())
+            .take_while(|l| *l != "#[cfg(test)]")
+            .to_owned_vec();
+        let end = lines.iter().rposition(|l| !l.is_empty()).map_default(0, |i| i + 1);
+        for line in lines.slice_to(end).iter() {
+            self.write_line(*line);
+        }
+    }
+
+    fn write_context_info_fns(&mut self) {
+        if !self.has_cmd("GetString") || !self.has_cmd("GetIntegerv") {
+            fail!("--context-info requires GetString and GetIntegerv to be generated");
+        }
+        self.write_line("/// Parsing of the version strings and state that describe a context");
+        self.write_line("pub mod context {");
+        self.incr_indent();
+        self.write_src(CONTEXT_SRC);
+        self.decr_indent();
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("/// Returns a description of the current context, or `None` if its version");
+        self.write_line("/// string could not be parsed. The functions must have been loaded first.");
+        self.write_line("///");
+        self.write_line("/// ~~~");
+        self.write_line("/// gl::load_with(glfw::get_proc_address);");
+        self.write_line("/// let info = gl::context_info().unwrap();");
+        self.write_line("/// if !info.version.is_at_least(3, 2) { fail!(\"OpenGL 3.2 is required\") }");
+        self.write_line("/// ~~~");
+        self.write_line("pub fn context_info() -> Option<context::ContextInfo> {");
+        self.write_line("    let get_string = |name: u32| unsafe {");
+        self.write_line(format!("        let s = {}(name);", self.gen_fn_ident("GetString")));
+        self.write_line("        if s.is_null() { ~\"\" } else { std::str::raw::from_c_str(s as *c_char) }");
+        self.write_line("    };");
+        self.write_line("    let get_integer = |name: u32| {");
+        self.write_line("        let mut value = 0;");
+        self.write_line(format!("        unsafe \\{ {}(name, &mut value) \\};", self.gen_fn_ident("GetIntegerv")));
+        self.write_line("        value");
+        self.write_line("    };");
+        self.write_line("    context::ContextInfo::from_queries(get_string, get_integer)");
+        self.write_line("}");
+    }
+
+    /// The name of the trait for a feature, such as `Gl33` for OpenGL 3.3
+    /// or `Gles20` for OpenGL ES 2.0.
+    fn gen_feature_trait_name(feature: &Feature) -> ~str {
+        let api = match feature.api.as_slice() {
+            "gl" => "Gl",
+            "gles1" | "gles2" => "Gles",
+            "glsc2" => "Glsc",
+            api => api,
+        };
+        api + feature.number.replace(".", "")
+    }
+
+    /// The name of the trait for an extension, such as `ArbDebugOutput` for
+    /// `GL_ARB_debug_output`.
+    fn gen_extension_trait_name(extension: &Extension) -> ~str {
+        escape_ident(extension.name.split_iter('_').skip(1)
+            .filter(|word| !word.is_empty())
+            .map(|word| word.slice_to(1).to_ascii_upper() + word.slice_from(1).to_ascii_lower())
+            .to_owned_vec()
+            .concat())
+    }
+
+    /// The name of the function checking for an extension, such as
+    /// `arb_debug_output` for `GL_ARB_debug_output`.
+    fn gen_extension_fn_name(extension: &Extension) -> ~str {
+        let name = extension.name.as_slice();
+        escape_ident(name.slice_from(name.find('_').map_default(0, |i| i + 1)).to_ascii_lower())
+    }
+
+    /// The commands required by a list of requires that are present in the
+    /// filtered registry
+    fn gen_required_cmds(&self, requires: &[Require]) -> ~[&'self Cmd] {
+        let reg = self.registry;
+        let mut cmds = ~[];
+        for req in requires.iter() {
+            for name in req.commands.iter() {
+                match reg.cmds.iter().find(|c| Generator::gen_symbol_name(&self.ns, *c) == *name) {
+                    Some(c) if !cmds.iter().any(|x: &&Cmd| x.proto.ident == c.proto.ident) => cmds.push(c),
+                    _ => (),
+                }
+            }
+        }
+        cmds
+    }
+
+    fn write_witness_trait(&self, doc: &str, name: &str, parent: Option<&str>, cmds: &[&Cmd]) {
+        self.write_line(format!("/// {}", doc));
+        self.write_line(format!("pub trait {}{} \\{", name, match parent {
+            Some(parent) => ": " + parent,
+            None => ~"",
+        }));
+        for c in cmds.iter() {
+            self.write_line(format!("    \\#[inline] {}fn {}(&self{}{}){} \\{ ::{}({}) \\}",
+                if c.is_safe { "" } else { "unsafe " },
+                self.gen_cmd_ident(*c),
+                if c.params.is_empty() { "" } else { ", " },
+                Generator::gen_param_list(*c, true),
+                Generator::gen_return_suffix(*c),
+                self.gen_cmd_ident(*c),
+                Generator::gen_param_ident_list(*c)));
+        }
+        self.write_line("}");
+        self.write_line("");
+    }
+
+    fn write_witness_check(&self, fn_name: &str, witness: &str, traits: &[~str], cond: &str, cmds: &[&Cmd]) {
+        self.write_line(format!("/// A witness that `{}` is available in the current context", traits[traits.len() - 1]));
+        self.write_line(format!("pub struct {} \\{ priv x: () \\}", witness));
+        for t in traits.iter() {
+            self.write_line(format!("impl {} for {} \\{\\}", *t, witness));
+        }
+        self.write_line("");
+        self.write_line(format!("/// Returns a `{}` if the current context supports it and all of its", witness));
+        self.write_line("/// functions were loaded");
+        self.write_line(format!("pub fn {}(info: &ContextInfo) -> Option<{}> \\{", fn_name, witness));
+        self.write_line(format!("    let loaded = {};", if cmds.is_empty() {
+            ~"true"
+        } else {
+            cmds.map(|c| format!("::{}::is_loaded()", self.gen_cmd_ident(*c))).connect(" && ")
+        }));
+        self.write_line(format!("    if loaded && {} \\{ Some({} \\{ x: () \\}) \\} else \\{ None \\}", cond, witness));
+        self.write_line("}");
+        self.write_line("");
+    }
+
+    fn write_witness_fns(&mut self) {
+        let reg = self.registry;
+        let mut features = reg.features.iter().to_owned_vec();
+        extra::sort::quick_sort(features, |a, b| (a.api.clone(), a.number.clone()) <= (b.api.clone(), b.number.clone()));
+
+        self.write_line("/// Traits for each feature level and extension, exposing only the commands");
+        self.write_line("/// that they require. A witness implementing a trait is handed out only once");
+        self.write_line("/// the context has been checked to support it and all of its functions have");
+        self.write_line("/// loaded, so code that takes the trait needs no `is_loaded()` checks.");
+        self.write_line("///");
+        self.write_line("/// ~~~");
+        self.write_line("/// gl::load_with(glfw::get_proc_address);");
+        self.write_line("/// let info = gl::context_info().unwrap();");
+        self.write_line("/// let gl33 = gl::features::gl33(&info).expect(\"OpenGL 3.3 is required\");");
+        self.write_line("/// draw(&gl33);");
+        self.write_line("///");
+        self.write_line("/// fn draw<G: gl::features::Gl33>(gl: &G) {");
+        self.write_line("///     gl.DrawArrays(gl::TRIANGLES, 0, 3);");
+        self.write_line("/// }");
+        self.write_line("/// ~~~");
+        self.write_line("pub mod features {");
+        self.incr_indent();
+        self.write_line("use std::libc::*;");
+        self.write_line("use super::context::*;");
+        self.write_line("use super::types::*;");
+        self.write_line("");
+
+        // each version inherits the trait of the previous version of its API
+        let mut prev: Option<(~str, ~[~str])> = None;
+        for f in features.iter() {
+            let name = Generator::gen_feature_trait_name(*f);
+            let parents = match prev {
+                Some((ref api, ref traits)) if *api == f.api => traits.clone(),
+                _ => ~[],
+            };
+            let cmds = self.gen_required_cmds(f.requires);
+            self.write_witness_trait(format!("The commands added by {}", f.name), name,
+                                     parents.last_opt().map(|p| p.as_slice()), cmds);
+            let traits = vec::append_one(parents, name.clone());
+            let mut all_cmds = ~[];
+            for g in features.iter().filter(|g| g.api == f.api && g.number <= f.number) {
+                all_cmds.push_all(self.gen_required_cmds(g.requires));
+            }
+            let (major, minor) = {
+                let mut numbers = f.number.split_iter('.').map(|n| n.to_owned());
+                (numbers.next().unwrap(), numbers.next().unwrap_or(~"0"))
+            };
+            let api = if f.api.starts_with("gles") { "EmbeddedApi" } else { "DesktopApi" };
+            self.write_witness_check(name.to_ascii_lower(), name + "Witness", traits,
+                                     format!("info.version.api == {} && info.version.is_at_least({}, {})",
+                                             api, major, minor),
+                                     all_cmds);
+            prev = Some((f.api.clone(), traits));
+        }
+
+        for e in reg.extensions.iter() {
+            let name = Generator::gen_extension_trait_name(e);
+            let cmds = self.gen_required_cmds(e.requires);
+            self.write_witness_trait(format!("The commands added by {}", e.name), name, None, cmds);
+            self.write_witness_check(Generator::gen_extension_fn_name(e),
+                                     name + "Witness", [name.clone()],
+                                     format!("has_extension(info, \"{}\")", e.name), cmds);
+        }
+
+        self.write_line("/// Returns true if the extension is in the context's list of extensions");
+        self.write_line("pub fn has_extension(info: &ContextInfo, name: &str) -> bool {");
+        self.incr_indent();
+        if self.has_cmd("GetStringi") {
+            self.write_line("if info.version.is_at_least(3, 0) {");
+            self.write_line("    let mut count = 0;");
+            self.write_line(format!("    unsafe \\{ ::{}(0x821D, &mut count) \\};  // NUM_EXTENSIONS",
+                                    self.gen_fn_ident("GetIntegerv")));
+            self.write_line("    return range(0, count as u32).any(|i| unsafe {");
+            self.write_line(format!("        ::std::str::raw::from_c_str(::{}(0x1F03, i) as *c_char).as_slice() == name",
+                                    self.gen_fn_ident("GetStringi")));
+            self.write_line("    });");
+            self.write_line("}");
+        }
+        self.write_line(format!("let extensions = ::{}(0x1F03);  // EXTENSIONS", self.gen_fn_ident("GetString")));
+        self.write_line("!extensions.is_null() && unsafe {");
+        self.write_line("    ::std::str::raw::from_c_str(extensions as *c_char).word_iter().any(|e| e == name)");
+        self.write_line("}");
+        self.decr_indent();
+        self.write_line("}");
+        self.decr_indent();
+        self.write_line("}");
+    }
+
+    fn write_array_overload(&self, scalar: &Cmd, vector: &Cmd) {
+        // the vector form replaces the trailing scalar parameters with a pointer
+        // to an array of the length given by its `len` attribute
+        let (ptr, leading) = match vector.params.last_opt() {
+            Some(ptr) => (ptr, vector.params.slice_to(vector.params.len() - 1)),
+            None => {
+                debug2!("Skipping vecequiv of {}", scalar.proto.ident);
+                return;
+            }
+        };
+        let len = match ptr.len {
+            Some(ref len) => from_str::<uint>(*len),
+            None => None,
+        };
+        let elem_ty = match (ty::to_rust_ty(ptr.ty), len) {
+            (ty, Some(len)) if ty.starts_with("*") && !ty.starts_with("*mut ") && !ty.starts_with("**")
+                               && scalar.params.len() == leading.len() + len
+                               && !leading.iter().any(|b| ty::to_rust_ty(b.ty).starts_with("*")) => {
+                ty.slice_from(1)
+            }
+            _ => {
+                debug2!("Skipping vecequiv of {}", scalar.proto.ident);
+                return;
+            }
+        };
+        let len = len.unwrap();
+        let ident = Generator::gen_binding_ident(ptr, true);
+        let params = leading.iter()
+            .map(|b| Generator::gen_binding(b, true))
+            .to_owned_vec() + ~[format!("{}: &[{}, ..{}]", ident, elem_ty, len)];
+        let args = leading.iter()
+            .map(|b| Generator::gen_binding_ident(b, true))
+            .to_owned_vec() + ~[format!("&{}[0] as *{}", ident, elem_ty)];
+        self.write_line(format!("\\#[inline] pub fn {}({}){} \\{ unsafe \\{ ::{}({}) \\} \\}",
+            self.gen_cmd_ident(vector),
+            params.connect(", "),
+            Generator::gen_return_suffix(vector),
+            self.gen_cmd_ident(vector),
+            args.connect(", ")));
+    }
+
+    fn write_array_overload_fns(&mut self) {
+        self.write_line("/// Safe versions of the vector forms of commands, taking fixed-size arrays in");
+        self.write_line("/// place of pointers, such as `gl::array::Color3fv(&[1.0, 0.0, 0.0])`.");
+        self.write_line("pub mod array {");
+        self.incr_indent();
+        self.write_line("use std::libc::*;");
+        self.write_line("use super::types::*;");
+        self.write_line("");
+        let reg = self.registry;
+        do self.for_cmds |c| {
+            match c.vecequiv {
+                Some(ref name) => match reg.cmds.iter().find(|v| Generator::gen_symbol_name(&self.ns, *v) == *name) {
+                    Some(v) => self.write_array_overload(c, v),
+                    None => (),
+                },
+                None => (),
+            }
+        }
+        self.decr_indent();
+        self.write_line("}");
+    }
+
+    /// Returns the size in bytes of a type in the GLX protocol, along with the
+    /// format of the expression converting a value of it to a `u64`.
+    fn gen_glx_scalar(ty: &str) -> Option<(uint, &'static str)> {
+        match ty {
+            "GLbyte" | "GLubyte" | "GLboolean" | "GLchar" => Some((1, "{} as u64")),
+            "GLshort" | "GLushort" | "GLhalf" | "GLhalfARB" | "GLhalfNV" => Some((2, "{} as u64")),
+            "GLenum" | "GLbitfield" | "GLuint" | "GLint" | "GLsizei" | "GLfixed" => Some((4, "{} as u64")),
+            "GLfloat" | "GLclampf" => Some((4, "unsafe { cast::transmute::<f32, u32>({}) } as u64")),
+            "GLdouble" | "GLclampd" => Some((8, "unsafe { cast::transmute::<f64, u64>({}) }")),
+            "GLint64" | "GLint64EXT" | "GLuint64" | "GLuint64EXT" => Some((8, "{} as u64")),
+            _ => None,
+        }
+    }
+
+    /// Returns the statements that encode a parameter, or `None` if it cannot
+    /// be encoded. Outputs are returned in the reply, so they are not encoded.
+    fn gen_glx_param(cmd: &Cmd, binding: &Binding) -> Option<~[~str]> {
+        let ident = Generator::gen_binding_ident(binding, true);
+        match ty::to_rust_ty(binding.ty) {
+            ty if ty.starts_with("*mut ") => Some(~[]),
+            ty if ty.starts_with("*") && !ty.starts_with("**") => {
+                let (size, conv) = match Generator::gen_glx_scalar(ty.slice_from(1)) {
+                    Some(scalar) => scalar,
+                    None => return None,
+                };
+                Generator::gen_capture_len(cmd, binding).map(|len| ~[
+                    format!("for i in range(0, {} as int) \\{", len),
+                    format!("    put(&mut bytes, {}, {});", conv.replace("{}", format!("*ptr::offset({}, i)", ident)), size),
+                    ~"}",
+                ])
+            }
+            ty => Generator::gen_glx_scalar(ty).map(|(size, conv)| ~[
+                format!("put(&mut bytes, {}, {});", conv.replace("{}", ident), size),
+            ]),
+        }
+    }
+
+    /// Whether the GLX protocol of a command is its parameters in declaration
+    /// order. The protocol moves 8 byte values ahead of smaller ones to keep
+    /// them aligned, so commands that declare them after smaller values are
+    /// laid out differently.
+    fn is_glx_plain(cmd: &Cmd) -> bool {
+        if GLX_SPECIAL_LAYOUTS.iter().any(|name| *name == cmd.proto.ident) {
+            return false;
+        }
+        let mut seen_small = false;
+        for binding in cmd.params.iter() {
+            let ty = ty::to_rust_ty(binding.ty);
+            let ty = if ty.starts_with("*") && !ty.starts_with("*mut ") { ty.slice_from(1) } else { ty };
+            match Generator::gen_glx_scalar(ty) {
+                Some((8, _)) if seen_small => return false,
+                Some((8, _)) => (),
+                _ => seen_small = true,
+            }
+        }
+        true
+    }
+
+    fn write_glx_encoder(&self, cmd: &Cmd, glx: &GlxOpcode) {
+        if !Generator::is_glx_plain(cmd) {
+            debug2!("Not encoding {}", cmd.proto.ident);
+            return;
+        }
+        let mut stmts = ~[];
+        for binding in cmd.params.iter() {
+            match Generator::gen_glx_param(cmd, binding) {
+                Some(s) => stmts.push_all_move(s),
+                None => {
+                    debug2!("Not encoding {}", cmd.proto.ident);
+                    return;
+                }
+            }
+        }
+        let opcode = match from_str::<uint>(glx.opcode) {
+            Some(opcode) => opcode,
+            None => return,
+        };
+        let (extra_params, header) = match glx.ty.as_slice() {
+            "render" => {
+                if cmd.params.iter().any(|b| ty::to_rust_ty(b.ty).starts_with("*mut ")) { return; }
+                (~"", ~"~[0, 0, 0, 0]")
+            }
+            "single" => (~"major_opcode: u8, context_tag: u32",
+                         format!("~[major_opcode, {}, 0, 0]", opcode)),
+            "vendor" => (~"major_opcode: u8, context_tag: u32",
+                         format!("~[major_opcode, {}, 0, 0]",
+                                 if Generator::gen_return_suffix(cmd).is_empty() { 16 } else { 17 })),
+            _ => return,
+        };
+        let params = Generator::gen_param_list(cmd, true);
+        self.write_line(format!("pub {}fn {}({}) -> ~[u8] \\{",
+            if cmd.is_safe { "" } else { "unsafe " },
+            self.gen_cmd_ident(cmd),
+            if extra_params.is_empty() { params } else if params.is_empty() { extra_params } else { extra_params + ", " + params }));
+        self.write_line(format!("    let mut bytes: ~[u8] = {};", header));
+        match glx.ty.as_slice() {
+            "single" => self.write_line("    put(&mut bytes, context_tag as u64, 4);"),
+            "vendor" => {
+                self.write_line(format!("    put(&mut bytes, {}, 4);", opcode));
+                self.write_line("    put(&mut bytes, context_tag as u64, 4);");
+            }
+            _ => (),
+        }
+        for stmt in stmts.iter() {
+            self.write_line("    " + *stmt);
+        }
+        match glx.ty.as_slice() {
+            "render" => self.write_line(format!("    finish_render(bytes, {})", opcode)),
+            _ => self.write_line("    finish_request(bytes)"),
+        }
+        self.write_line("}");
+    }
+
+    fn write_glx_protocol_fns(&mut self) {
+        self.write_line("/// Encoders for the GLX protocol requests of each command, for indirect");
+        self.write_line("/// rendering or X protocol proxies. Render commands are returned without a");
+        self.write_line("/// request header, ready to be batched into a `glXRender` request, while single");
+        self.write_line("/// and vendor private requests are returned whole. Values are encoded in");
+        self.write_line("/// little-endian byte order, in the order that the parameters are declared.");
+        self.write_line("/// Commands with parameters of unknown size, or whose protocol is laid out");
+        self.write_line("/// differently from their parameters, are not generated.");
+        self.write_line("pub mod protocol {");
+        self.incr_indent();
+        self.write_line("use std::cast;");
+        self.write_line("use std::libc::*;");
+        self.write_line("use std::ptr;");
+        self.write_line("use super::types::*;");
+        self.write_line("");
+        self.write_src(PROTOCOL_SRC);
+        self.write_line("");
+        do self.for_cmds |c| {
+            match c.glx {
+                Some(ref glx) => self.write_glx_encoder(c, glx),
+                None => (),
+            }
+        }
+        self.decr_indent();
+        self.write_line("}");
+    }
+
+    /// The vendor and name modules of an extension, such as `arb` and
+    /// `debug_output` for `GL_ARB_debug_output`
+    fn gen_extension_mod_path(name: &str) -> (~str, ~str) {
+        let name = name.slice_from(name.find('_').map_default(0, |i| i + 1)).to_ascii_lower();
+        let (vendor, ext) = match name.find('_') {
+            Some(i) => (name.slice_to(i).to_owned(), name.slice_from(i + 1).to_owned()),
+            None => (name.clone(), ~"ext"),
+        };
+        (escape_ident(vendor), escape_ident(ext))
+    }
+
+    /// The enums required by a list of requires that are present in the
+    /// filtered registry
+    fn gen_required_enums(&self, requires: &[Require]) -> ~[&'self Enum] {
+        let reg = self.registry;
+        let mut enums = ~[];
+        for req in requires.iter() {
+            for name in req.enums.iter() {
+                match reg.enums.iter().find(|e| self.gen_enum_name(*e) == *name) {
+                    Some(e) if !enums.iter().any(|x: &&Enum| x.ident == e.ident) => enums.push(e),
+                    _ => (),
+                }
+            }
+        }
+        enums
+    }
+
+    /// Writes the module of an extension. The module refers to `types` and
+    /// `FnPtr` at the crate root, so it only namespaces the items, while
+    /// `write_extension_crates` writes them as separate crates.
+    fn write_extension_module(&mut self, ext: &Extension, name: &str) {
+        self.write_line(format!("/// The enums and commands of `{}`", ext.name));
+        self.write_line(format!("pub mod {} \\{", name));
+        self.incr_indent();
+        self.write_extension_items(ext, "");
+        self.decr_indent();
+        self.write_line("}");
+    }
+
+    /// Writes the items of an extension. Items that were promoted to core are
+    /// re-exported from the core bindings, and the rest are defined along with
+    /// their own function pointers, loader and `unload`. The core bindings are
+    /// reached through `root`, which is empty within the core crate, or the
+    /// name of the core crate followed by `::`.
+    fn write_extension_items(&mut self, ext: &Extension, root: &str) {
+        let enums = self.gen_required_enums(ext.requires);
+        let cmds = self.gen_required_cmds(ext.requires);
+        let (core_enums, ext_enums) = enums.partitioned(|e| self.is_core(self.gen_enum_name(*e)));
+        let (core_cmds, ext_cmds) = cmds.partitioned(|c| self.is_core(Generator::gen_symbol_name(&self.ns, *c)));
+
+        self.write_line("use std::libc::*;");
+        self.write_line(format!("use {}types::*;", root));
+        if !ext_cmds.is_empty() {
+            self.write_line(format!("use {}FnPtr;", root));
+        }
+        for e in core_enums.iter() {
+            self.write_line(format!("pub use {}{};", root, self.gen_enum_ident(e.ident)));
+        }
+        for c in core_cmds.iter() {
+            self.write_line(format!("pub use {}{};", root, self.gen_cmd_ident(*c)));
+        }
+        self.write_line("");
+        for e in ext_enums.iter() {
+            self.write_enum(*e, "GLenum");
+        }
+        if !ext_enums.is_empty() {
+            self.write_line("");
+        }
+
+        if ext_cmds.is_empty() {
+            self.write_line("pub fn load_with(_: &fn(symbol: &str) -> Option<extern \"C\" fn()>) -> bool { true }");
+            self.write_line("pub fn is_loaded() -> bool { true }");
+            self.write_line("pub fn unload() {}");
+            return;
+        }
+
+        self.write_line("mod failing {");
+        self.incr_indent();
+        self.write_line("use std::libc::*;");
+        self.write_line(format!("use {}types::*;", root));
+        self.write_line("");
+        for c in ext_cmds.iter() {
+            let params = c.params.map(|b| format!("_: {}", ty::to_rust_ty(b.ty))).connect(", ");
+            self.write_line(format!("pub extern \"C\" fn {}({}){} \\{ fail!(\"{} was not loaded\") \\}",
+                self.gen_cmd_ident(*c), params, Generator::gen_return_suffix(*c),
+                Generator::gen_symbol_name(&self.ns, *c)));
+        }
+        self.decr_indent();
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("mod storage {");
+        self.incr_indent();
+        self.write_line("use std::libc::*;");
+        self.write_line(format!("use {}types::*;", root));
+        self.write_line(format!("use {}FnPtr;", root));
+        self.write_line("");
+        for c in ext_cmds.iter() {
+            let ident = self.gen_cmd_ident(*c);
+            self.write_line(format!("pub static mut {}: FnPtr<extern \"C\" fn({}){}> = FnPtr \\{ f: super::failing::{}, is_loaded: false \\};",
+                ident, Generator::gen_param_list(*c, true), Generator::gen_return_suffix(*c), ident));
+        }
+        self.decr_indent();
+        self.write_line("}");
+        self.write_line("");
+        for c in ext_cmds.iter() {
+            let ident = self.gen_cmd_ident(*c);
+            self.write_line(format!("\\#[fixed_stack_segment] \\#[inline] pub {}fn {}({}){} \\{ {}(storage::{}.f)({}){} \\}",
+                if c.is_safe { "" } else { "unsafe " },
+                ident,
+                Generator::gen_param_list(*c, true),
+                Generator::gen_return_suffix(*c),
+                if c.is_safe { "unsafe { " } else { "" },
+                ident,
+                Generator::gen_param_ident_list(*c),
+                if c.is_safe { " }" } else { "" }));
+        }
+        self.write_line("");
+        self.write_line(format!("/// Loads the commands of `{}`, returning `true` if all of them were", ext.name));
+        self.write_line("/// found. Commands that were promoted to core are loaded by `gl::load_with`.");
+        self.write_line("pub fn load_with(loadfn: &fn(symbol: &str) -> Option<extern \"C\" fn()>) -> bool {");
+        self.write_line("    unsafe {");
+        for c in ext_cmds.iter() {
+            let ident = self.gen_cmd_ident(*c);
+            self.write_line(format!("        storage::{} = FnPtr::new(loadfn(\"{}\"), failing::{});",
+                ident, Generator::gen_symbol_name(&self.ns, *c), ident));
+        }
+        self.write_line("    }");
+        self.write_line("    is_loaded()");
+        self.write_line("}");
+        self.write_line("");
+        self.write_line(format!("/// Returns `true` if every command of `{}` has been loaded", ext.name));
+        self.write_line(format!("pub fn is_loaded() -> bool \\{ unsafe \\{ {} \\} \\}",
+            ext_cmds.map(|c| format!("storage::{}.is_loaded", self.gen_cmd_ident(*c))).connect(" && ")));
+        self.write_line("");
+        self.write_line(format!("/// Restores the commands of `{}` to their unloaded state. This is", ext.name));
+        self.write_line(if root.is_empty() { "/// called by `gl::unload`." } else { "/// not called by `gl::unload`, so it must be called along with it." });
+        self.write_line("pub fn unload() {");
+        self.write_line("    unsafe {");
+        for c in ext_cmds.iter() {
+            let ident = self.gen_cmd_ident(*c);
+            self.write_line(format!("        storage::{} = FnPtr \\{ f: failing::{}, is_loaded: false \\};", ident, ident));
+        }
+        self.write_line("    }");
+        self.write_line("}");
+    }
+
+    fn write_extension_modules(&mut self) {
+        let reg = self.registry;
+        let mut exts = reg.extensions.iter()
+            .map(|e| {
+                let (vendor, name) = Generator::gen_extension_mod_path(e.name);
+                (vendor, name, e)
+            })
+            .to_owned_vec();
+        extra::sort::quick_sort(exts, |&(ref va, ref na, _), &(ref vb, ref nb, _)| {
+            if *va == *vb { *na <= *nb } else { *va <= *vb }
+        });
+
+        let mut prev_vendor: Option<~str> = None;
+        for &(ref vendor, ref name, ext) in exts.iter() {
+            if prev_vendor.as_ref() != Some(vendor) {
+                if prev_vendor.is_some() {
+                    self.decr_indent();
+                    self.write_line("}");
+                    self.write_line("");
+                }
+                self.write_line(format!("/// The extensions from the `{}` vendor", vendor.to_ascii_upper()));
+                self.write_line(format!("pub mod {} \\{", *vendor));
+                self.incr_indent();
+                prev_vendor = Some(vendor.clone());
+            } else {
+                self.write_line("");
+            }
+            self.write_extension_module(ext, *name);
+        }
+        if prev_vendor.is_some() {
+            self.decr_indent();
+            self.write_line("}");
+        }
+    }
+
+    /// Writes each extension to `dir` as its own crate, such as
+    /// `gl_arb_debug_output.rs`, which only depends on the core crate. Each
+    /// crate can then be built separately from the others.
+    fn write_extension_crates(&mut self, dir: &str) {
+        let core = self.ns.to_str();
+        let core_writer = self.writer;
+        let reg = self.registry;
+        for ext in reg.extensions.iter() {
+            let (vendor, name) = Generator::gen_extension_mod_path(ext.name);
+            let crate_name = format!("{}_{}_{}", core, vendor, name);
+            let path = Path::new(dir).join((crate_name + ".rs").as_slice());
+            self.writer = match io::file_writer(&path, [io::Create, io::Truncate]) {
+                Ok(writer) => writer,
+                Err(e) => fail!("Could not write {}: {}", path.display(), e),
+            };
+            self.write_licence();
+            self.write_line("");
+            self.write_line(format!("\\#[link(name = \"{}\", vers = \"0.1\")];", crate_name));
+            self.write_line("#[crate_type = \"lib\"];");
+            self.write_line("#[feature(globs)];");
+            self.write_line("");
+            self.write_line(format!("//! The enums and commands of `{}`, for use with the `{}` crate", ext.name, core));
+            self.write_line("");
+            self.write_line(format!("extern mod {};", core));
+            self.write_line("");
+            self.write_extension_items(ext, (core + "::").as_slice());
+        }
+        self.writer = core_writer;
+    }
+
+    fn write_struct_def(&mut self) {
+        self.write_line("/// The function pointers of one context. Each context may return different");
+        self.write_line("/// pointers, so they are loaded into a `Gl` for each context rather than into");
+        self.write_line("/// globals.");
+        self.write_line("pub struct Gl {");
+        self.incr_indent();
+        self.for_cmds(
+            |c| self.write_line(format!("{}: FnPtr<extern \"C\" fn({}){}>,",
+                self.gen_cmd_ident(c),
+                Generator::gen_param_list(c, true),
+                Generator::gen_return_suffix(c)))
+        );
+        self.decr_indent();
+        self.write_line("}");
+        self.write_line("");
+        self.write_line("impl Gl {");
+        self.incr_indent();
+        self.write_line("/// Loads each OpenGL symbol using a custom load function, such as");
+        self.write_line("/// `glfwGetProcAddress` or `SDL_GL_GetProcAddress`, while the context is");
+        self.write_line("/// current. Symbols that could not be loaded fail when they are called.");
+        self.write_line("pub fn load_with(loadfn: &fn(symbol: &str) -> Option<extern \"C\" fn()>) -> Gl {");
+        self.write_line("    Gl {");
+        self.for_cmds(
+            |c| self.write_line(format!("        {}: FnPtr::new(loadfn(\"{}\"), failing::{}),",
+                self.gen_cmd_ident(c),
+                Generator::gen_symbol_name(&self.ns, c),
+                self.gen_cmd_ident(c)))
+        );
+        self.write_line("    }");
+        self.write_line("}");
+        self.write_line("");
+        do self.for_cmds |c| {
+            let ident = self.gen_cmd_ident(c);
+            self.write_deprecation(Generator::gen_symbol_name(&self.ns, c));
+            self.write_line(format!(
+                "\\#[fixed_stack_segment] \\#[inline] pub {}fn {}(&self{}){} \\{ {}(self.{}.f)({}){} \\}",
+                if c.is_safe { "" } else { "unsafe " },
+                ident,
+                if c.params.is_empty() { ~"" } else { ", " + Generator::gen_param_list(c, true) },
+                Generator::gen_return_suffix(c),
+                if c.is_safe { "unsafe { " } else { "" },
+                ident,
+                Generator::gen_param_ident_list(c),
+                if c.is_safe { " }" } else { "" }));
+        }
+        self.decr_indent();
+        self.write_line("}");
+    }
+
+    /// Writes bindings in which the function pointers are the fields of a `Gl`
+    /// struct, instead of globals
+    fn write_struct(writer: @Writer, reg: &Registry, ns: Ns, options: &Options) {
+        let mut gen = Generator::new(writer, reg, ns, options);
+        gen.check_collisions();
+
+        gen.write_header();
+        gen.write_line("");
+        gen.write_type_aliases();
+        gen.write_line("");
+        gen.write_enums();
+        gen.write_line("");
+        gen.write_fnptr_struct_def();
+        gen.write_line("");
+        gen.write_failing_fns();
+        gen.write_line("");
+        gen.write_struct_def();
+    }
+
+    fn write(writer: @Writer, reg: &Registry, ns: Ns, options: &Options) {
+        let mut gen = Generator::new(writer, reg, ns, options);
+        gen.check_collisions();
+
+        // header with licence, metadata and imports
+        gen.write_header();
+        gen.write_line("");
 
         // type aliases
         gen.write_type_aliases();
@@ -423,7 +3210,11 @@ impl<'self> Generator<'self> {
         gen.write_line("");
 
         // FnPtr struct def
-        gen.write_fnptr_struct_def();
+        if gen.options.atomic {
+            gen.write_atomic_fnptr_struct_def();
+        } else {
+            gen.write_fnptr_struct_def();
+        }
         gen.write_line("");
 
         // static muts for storing function pointers
@@ -438,8 +3229,661 @@ impl<'self> Generator<'self> {
         gen.write_failing_fns();
         gen.write_line("");
 
-        // loader function
-        gen.write_load_fn();
+        if gen.options.lazy {
+            // functions that load their symbol on the first call
+            gen.write_lazy_fns();
+            gen.write_line("");
+
+            // loader function
+            gen.write_lazy_load_fn();
+            gen.write_line("");
+        } else {
+            // report of missing symbols
+            gen.write_load_report_struct_def();
+            gen.write_line("");
+
+            // loader function
+            gen.write_load_fn();
+            gen.write_line("");
+        }
+
+        // unload function
+        gen.write_unload_fn();
         gen.write_line("");
+
+        // reverse lookup of enum values
+        if gen.options.enum_names {
+            gen.write_enum_name_fns();
+            gen.write_line("");
+        }
+
+        // typed error retrieval
+        if gen.options.errors {
+            gen.write_error_fns();
+            gen.write_line("");
+        }
+
+        // debug output callbacks
+        if gen.options.debug_output {
+            gen.write_debug_output_fns();
+            gen.write_line("");
+        }
+
+        // recording mock implementation
+        if gen.options.mock {
+            gen.write_mock_fns();
+            gen.write_line("");
+        }
+
+        // emulated object state for the mock implementation
+        if gen.options.emulate {
+            gen.write_emulate_fns();
+            gen.write_line("");
+        }
+
+        // call stream capture and replay
+        if gen.options.capture {
+            gen.write_capture_fns();
+            gen.write_line("");
+        }
+
+        // call counters
+        if gen.options.stats {
+            gen.write_stats_fns();
+            gen.write_line("");
+        }
+
+        // context version and profile detection
+        if gen.options.context_info {
+            gen.write_context_info_fns();
+            gen.write_line("");
+        }
+
+        // fixed-size array overloads
+        if gen.options.array_overloads {
+            gen.write_array_overload_fns();
+            gen.write_line("");
+        }
+
+        // GLX protocol encoders
+        if gen.options.glx_protocol {
+            gen.write_glx_protocol_fns();
+            gen.write_line("");
+        }
+
+        // feature level and extension witnesses
+        if gen.options.witnesses {
+            gen.write_witness_fns();
+            gen.write_line("");
+        }
+
+        // per-extension modules, or crates
+        if gen.options.modules {
+            match gen.options.modules_dir.clone() {
+                Some(dir) => gen.write_extension_crates(dir.as_slice()),
+                None => {
+                    gen.write_extension_modules();
+                    gen.write_line("");
+                }
+            }
+        }
+
+        // typed state queries
+        if gen.options.state {
+            gen.write_state_fns();
+            gen.write_line("");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Generator, Options, TrimmedNames, CNames, SnakeCaseNames};
+    use super::{Backend, Backends};
+    use super::{escape_keyword, to_snake_case};
+    use registry::*;
+    use std::io;
+    use std::io::Writer;
+    use std::os;
+
+    static FIXTURE: &'static str = include_str!("fixtures/registry.xml");
+
+    fn options() -> Options {
+        Options {
+            feature_level: ~"gl 3.2 core",
+            state: false,
+            enum_names: false,
+            errors: false,
+            debug_output: false,
+            lazy: false,
+            atomic: false,
+            mock: false,
+            emulate: false,
+            capture: false,
+            stats: false,
+            context_info: false,
+            witnesses: false,
+            array_overloads: false,
+            glx_protocol: false,
+            gated: None,
+            modules: false,
+            modules_dir: None,
+            naming: TrimmedNames,
+        }
+    }
+
+    fn filter(version: &str, profile: &str, extensions: &[&str]) -> Option<Filter> {
+        Some(Filter {
+            extensions: extensions.iter().map(|s| s.to_owned()).to_owned_vec(),
+            profile: profile.to_owned(),
+            version: version.to_owned(),
+            api: ~"gl",
+        })
+    }
+
+    fn generate(filter: Option<Filter>, options: &Options) -> ~str {
+        let reg = Registry::from_xml(FIXTURE, Gl, filter);
+        io::with_str_writer(|w| Generator::write(w, &reg, Gl, options))
+    }
+
+    fn has_line(output: &str, line: &str) -> bool {
+        output.line_iter().any(|l| l.trim() == line)
+    }
+
+    #[test]
+    fn test_enums() {
+        let output = generate(filter("3.2", "core", []), &options());
+        assert!(has_line(output, "pub static TRIANGLES: GLenum = 0x0004;"));
+        assert!(has_line(output, "pub static NO_ERROR: GLenum = 0;"));
+        // identifiers cannot start with a digit
+        assert!(has_line(output, "pub static _2D: GLenum = 0x0600;"));
+        // removed from the core profile
+        assert!(!output.contains("CURRENT_COLOR"));
+    }
+
+    #[test]
+    fn test_fns() {
+        let output = generate(filter("3.2", "core", []), &options());
+        assert!(has_line(output, "#[fixed_stack_segment] #[inline] pub fn DrawArrays(mode: GLenum, first: GLint, count: GLsizei) \
+                                  { unsafe { (storage::DrawArrays.f)(mode, first, count) } }"));
+        assert!(has_line(output, "#[fixed_stack_segment] #[inline] pub fn GetError() -> GLenum \
+                                  { unsafe { (storage::GetError.f)() } }"));
+        assert!(has_line(output, "#[fixed_stack_segment] #[inline] pub unsafe fn BufferData(target: GLenum, size: GLsizeiptr, data: *c_void, usage: GLenum) \
+                                  { (storage::BufferData.f)(target, size, data, usage) }"));
+        assert!(has_line(output, "fn_ptr!(fn DrawArrays(mode: GLenum, first: GLint, count: GLsizei))"));
+        assert!(has_line(output, "failing!(fn GetError() -> GLenum)"));
+        assert!(has_line(output, "fn_mod!(BufferData, \"glBufferData\")"));
+        assert!(has_line(output, "report.record(\"glGetError\", GetError::load_with(|s| loadfn(s)));"));
+        assert!(has_line(output, "GetError::unload();"));
+        assert!(!output.contains("Color3f"));
+        assert!(!output.contains("DrawArraysEXT"));
+    }
+
+    #[test]
+    fn test_feature_levels() {
+        let output = generate(filter("1.0", "core", []), &options());
+        assert!(!output.contains("BufferData"));
+
+        let output = generate(filter("3.2", "compatibility", []), &options());
+        assert!(has_line(output, "pub static CURRENT_COLOR: GLenum = 0x0B00;"));
+        assert!(has_line(output, "fn_mod!(Color3fv, \"glColor3fv\")"));
+
+        let output = generate(filter("3.2", "core", ["GL_EXT_vertex_array"]), &options());
+        assert!(has_line(output, "fn_mod!(DrawArraysEXT, \"glDrawArraysEXT\")"));
+
+        let output = generate(None, &options());
+        for ident in ["DrawArrays", "DrawArraysEXT", "GetError", "Color3f", "Color3fv", "BufferData"].iter() {
+            assert!(has_line(output, format!("fn_mod!({}, \"gl{}\")", *ident, *ident)));
+        }
+    }
+
+    #[test]
+    fn test_load_report() {
+        let output = generate(filter("3.2", "core", []), &options());
+        assert!(has_line(output, "let mut report = LoadReport { requested: \"gl 3.2 core\", loaded: ~[], missing: ~[] };"));
+    }
+
+    #[test]
+    fn test_lazy() {
+        let output = generate(filter("3.2", "core", []), &Options { lazy: true, ..options() });
+        assert!(has_line(output, "mod lazy {"));
+        assert!(!has_line(output, "pub struct LoadReport {"));
+    }
+
+    #[test]
+    fn test_atomic() {
+        let output = generate(filter("3.2", "core", []), &Options { atomic: true, ..options() });
+        assert!(output.contains("(storage::DrawArrays.f())(mode, first, count)"));
+        assert!(output.contains("pub struct AtomicFnPtr<F> { ptr:"));
+    }
+
+    fn gate_of(output: &str, line: &str) -> ~[~str] {
+        let lines = output.line_iter().map(|l| l.trim()).to_owned_vec();
+        let i = lines.iter().position(|l| l.starts_with(line)).expect(line.to_owned());
+        lines.slice_to(i).rev_iter()
+            .take_while(|l| l.starts_with("#[cfg("))
+            .map(|l| l.to_owned())
+            .to_owned_vec()
+    }
+
+    #[test]
+    fn test_deprecations() {
+        let output = generate(filter("3.2", "compatibility", []), &options());
+        let lines = output.line_iter().map(|l| l.trim()).to_owned_vec();
+        let preceding = |line: &str| {
+            let i = lines.iter().position(|l| l.starts_with(line)).expect(line.to_owned());
+            lines[i - 1]
+        };
+        let note = "#[deprecated = \"removed from the core profile in gl 3.2\"]";
+        assert_eq!(preceding("pub static CURRENT_COLOR"), note);
+        assert_eq!(preceding("#[fixed_stack_segment] #[inline] pub fn Color3f("), note);
+        assert_eq!(preceding("#[fixed_stack_segment] #[inline] pub unsafe fn Color3fv("), note);
+        assert!(preceding("#[fixed_stack_segment] #[inline] pub fn DrawArrays(") != note);
+        assert!(!preceding("pub static TRIANGLES").starts_with("#[deprecated"));
+
+        // removals by versions after the filtered one are reported too
+        let output = generate(filter("1.5", "compatibility", []), &options());
+        assert!(has_line(output, note));
+        let lines = output.line_iter().map(|l| l.trim()).to_owned_vec();
+        let i = lines.iter().position(|l| l.starts_with("#[fixed_stack_segment] #[inline] pub fn Color3f(")).unwrap();
+        assert_eq!(lines[i - 1], note);
+
+        // removed items are not generated for the core profile
+        let output = generate(filter("3.2", "core", []), &options());
+        assert!(!output.contains("#[deprecated"));
+    }
+
+    #[test]
+    fn test_gated() {
+        let output = generate(None, &Options { gated: Some(~"gl"), ..options() });
+        assert_eq!(gate_of(output, "pub static TRIANGLES"),
+                   ~[~"#[cfg(gl_4_3)]", ~"#[cfg(gl_3_2)]", ~"#[cfg(gl_1_5)]", ~"#[cfg(gl_1_0)]"]);
+        assert_eq!(gate_of(output, "pub static DEBUG_OUTPUT_SYNCHRONOUS:"), ~[~"#[cfg(gl_4_3)]"]);
+        assert_eq!(gate_of(output, "pub static DEBUG_OUTPUT_SYNCHRONOUS_ARB"), ~[~"#[cfg(GL_ARB_debug_output)]"]);
+        assert_eq!(gate_of(output, "#[fixed_stack_segment] #[inline] pub fn Color3f("),
+                   ~[~"#[cfg(gl_4_3, not(gl_core))]", ~"#[cfg(gl_3_2, not(gl_core))]",
+                     ~"#[cfg(gl_1_5)]", ~"#[cfg(gl_1_0)]"]);
+        assert!(has_line(output, "if cfg!(GL_EXT_vertex_array) {"));
+        assert!(has_line(output, "if cfg!(gl_1_5) || cfg!(gl_3_2) || cfg!(gl_4_3) {"));
+    }
+
+    #[test]
+    fn test_modules() {
+        let output = generate(filter("4.3", "core", ["GL_ARB_debug_output", "GL_EXT_vertex_array"]),
+                              &Options { modules: true, ..options() });
+        let lines = output.line_iter().to_owned_vec();
+        // core items stay at the top level
+        assert!(lines.contains(&"pub static DEBUG_OUTPUT_SYNCHRONOUS: GLenum = 0x8242;"));
+        assert!(lines.iter().any(|l| l.starts_with("#[fixed_stack_segment] #[inline] pub fn DrawArrays(")));
+        // extension items are only defined in their modules
+        assert!(!lines.iter().any(|l| l.starts_with("pub static DEBUG_OUTPUT_SYNCHRONOUS_ARB")));
+        assert!(!lines.iter().any(|l| l.starts_with("#[fixed_stack_segment] #[inline] pub fn DrawArraysEXT(")));
+        assert!(lines.contains(&"pub mod arb {"));
+        assert!(lines.contains(&"    pub mod debug_output {"));
+        assert!(lines.contains(&"        pub static DEBUG_OUTPUT_SYNCHRONOUS_ARB: GLenum = 0x8242;"));
+        assert!(lines.contains(&"pub mod ext {"));
+        assert!(lines.contains(&"    pub mod vertex_array {"));
+        assert!(lines.contains(&"        #[fixed_stack_segment] #[inline] pub fn DrawArraysEXT(mode: GLenum, first: GLint, count: GLsizei) \
+                                 { unsafe { (storage::DrawArraysEXT.f)(mode, first, count) } }"));
+        assert!(has_line(output, "storage::DrawArraysEXT = FnPtr::new(loadfn(\"glDrawArraysEXT\"), failing::DrawArraysEXT);"));
+        assert!(has_line(output, "pub fn is_loaded() -> bool { unsafe { storage::DrawArraysEXT.is_loaded } }"));
+        // unloaded along with the core commands
+        assert!(has_line(output, "storage::DrawArraysEXT = FnPtr { f: failing::DrawArraysEXT, is_loaded: false };"));
+        assert!(has_line(output, "ext::vertex_array::unload();"));
+        assert!(has_line(output, "arb::debug_output::unload();"));
+
+        // items promoted to core are re-exported
+        let output = generate(None, &Options { modules: true, ..options() });
+        assert!(has_line(output, "pub mod texture_3d {"));
+        assert!(has_line(output, "pub use _2D;"));
+    }
+
+    #[test]
+    fn test_modules_dir() {
+        let dir = os::tmpdir().join("glgen_test_modules_dir");
+        assert!(os::mkdir_recursive(&dir, 0x1ff));
+        let output = generate(filter("4.3", "core", ["GL_ARB_debug_output", "GL_EXT_vertex_array"]), &Options {
+            modules: true, modules_dir: Some(dir.as_str().unwrap().to_owned()), ..options()
+        });
+        // the extensions are left out of the core crate, which cannot unload them
+        assert!(!has_line(output, "pub mod ext {"));
+        assert!(!has_line(output, "ext::vertex_array::unload();"));
+
+        let ext = io::read_whole_file_str(&dir.join("gl_ext_vertex_array.rs")).unwrap();
+        assert!(has_line(ext, "#[link(name = \"gl_ext_vertex_array\", vers = \"0.1\")];"));
+        assert!(has_line(ext, "extern mod gl;"));
+        assert!(has_line(ext, "use gl::types::*;"));
+        assert!(has_line(ext, "use gl::FnPtr;"));
+        assert!(has_line(ext, "storage::DrawArraysEXT = FnPtr::new(loadfn(\"glDrawArraysEXT\"), failing::DrawArraysEXT);"));
+        assert!(has_line(ext, "/// not called by `gl::unload`, so it must be called along with it."));
+
+        let arb = io::read_whole_file_str(&dir.join("gl_arb_debug_output.rs")).unwrap();
+        assert!(has_line(arb, "pub static DEBUG_OUTPUT_SYNCHRONOUS_ARB: GLenum = 0x8242;"));
+        assert!(has_line(arb, "use gl::types::*;"));
+        assert!(has_line(arb, "pub fn unload() {}"));
+    }
+
+    #[test]
+    fn test_context_info() {
+        let xml = "<registry><commands namespace=\"GL\">\
+            <command><proto>const <ptype>GLubyte</ptype> *<name>glGetString</name></proto>\
+                <param><ptype>GLenum</ptype> <name>name</name></param></command>\
+            <command><proto>void <name>glGetIntegerv</name></proto>\
+                <param><ptype>GLenum</ptype> <name>pname</name></param>\
+                <param>const <ptype>GLint</ptype> *<name>data</name></param></command>\
+            </commands></registry>";
+        let reg = Registry::from_xml(xml, Gl, None);
+        let output = io::with_str_writer(|w| Generator::write(w, &reg, Gl, &Options { context_info: true, ..options() }));
+        assert!(has_line(output, "pub mod context {"));
+        assert!(has_line(output, "pub fn context_info() -> Option<context::ContextInfo> {"));
+        // the tests of context.rs are not copied into the bindings
+        assert!(!output.contains("#[cfg(test)]"));
+        assert!(!output.contains("#[test]"));
+    }
+
+    #[test]
+    fn test_witnesses() {
+        let output = generate(filter("1.5", "core", ["GL_EXT_vertex_array"]), &Options { witnesses: true, ..options() });
+        assert!(has_line(output, "pub trait Gl10 {"));
+        assert!(has_line(output, "#[inline] fn DrawArrays(&self, mode: GLenum, first: GLint, count: GLsizei) { ::DrawArrays(mode, first, count) }"));
+        assert!(has_line(output, "#[inline] unsafe fn Color3fv(&self, v: *GLfloat) { ::Color3fv(v) }"));
+        assert!(has_line(output, "pub trait Gl15: Gl10 {"));
+        assert!(has_line(output, "impl Gl10 for Gl15Witness {}"));
+        assert!(has_line(output, "impl Gl15 for Gl15Witness {}"));
+        assert!(has_line(output, "pub fn gl15(info: &ContextInfo) -> Option<Gl15Witness> {"));
+        assert!(has_line(output, "if loaded && info.version.api == DesktopApi && info.version.is_at_least(1, 5) \
+                                  { Some(Gl15Witness { x: () }) } else { None }"));
+        assert!(has_line(output, "pub trait ExtVertexArray {"));
+        assert!(has_line(output, "let loaded = ::DrawArraysEXT::is_loaded();"));
+        assert!(has_line(output, "if loaded && has_extension(info, \"GL_EXT_vertex_array\") \
+                                  { Some(ExtVertexArrayWitness { x: () }) } else { None }"));
+
+        // names starting with a digit are prefixed
+        let ext = Extension { name: ~"GL_3DFX_tbuffer", supported: ~[~"gl"], requires: ~[] };
+        assert_eq!(Generator::gen_extension_trait_name(&ext), ~"_3dfxTbuffer");
+        assert_eq!(Generator::gen_extension_fn_name(&ext), ~"_3dfx_tbuffer");
+    }
+
+    #[test]
+    fn test_array_overloads() {
+        let output = generate(filter("1.0", "core", []), &Options { array_overloads: true, ..options() });
+        assert!(has_line(output, "pub mod array {"));
+        assert!(has_line(output, "#[inline] pub fn Color3fv(v: &[GLfloat, ..3]) { unsafe { ::Color3fv(&v[0] as *GLfloat) } }"));
+
+        // the compatibility profile keeps the removed commands
+        let output = generate(filter("3.2", "compatibility", []), &Options { array_overloads: true, ..options() });
+        assert!(output.contains("::Color3fv(&v[0] as *GLfloat)"));
+
+        // vector forms with several pointers, or whose length does not match
+        // the scalar form, are skipped
+        let xml = "<registry><commands namespace=\"GL\">\
+            <command><proto>void <name>glRectd</name></proto>\
+                <param><ptype>GLdouble</ptype> <name>x1</name></param>\
+                <param><ptype>GLdouble</ptype> <name>y1</name></param>\
+                <param><ptype>GLdouble</ptype> <name>x2</name></param>\
+                <param><ptype>GLdouble</ptype> <name>y2</name></param>\
+                <vecequiv name=\"glRectdv\"/></command>\
+            <command><proto>void <name>glRectdv</name></proto>\
+                <param len=\"2\">const <ptype>GLdouble</ptype> *<name>v1</name></param>\
+                <param len=\"2\">const <ptype>GLdouble</ptype> *<name>v2</name></param></command>\
+            <command><proto>void <name>glColor4f</name></proto>\
+                <param><ptype>GLfloat</ptype> <name>red</name></param>\
+                <param><ptype>GLfloat</ptype> <name>green</name></param>\
+                <param><ptype>GLfloat</ptype> <name>blue</name></param>\
+                <param><ptype>GLfloat</ptype> <name>alpha</name></param>\
+                <vecequiv name=\"glColor4fv\"/></command>\
+            <command><proto>void <name>glColor4fv</name></proto>\
+                <param len=\"3\">const <ptype>GLfloat</ptype> *<name>v</name></param></command>\
+            </commands></registry>";
+        let reg = Registry::from_xml(xml, Gl, None);
+        let output = io::with_str_writer(|w| Generator::write(w, &reg, Gl, &Options { array_overloads: true, ..options() }));
+        assert!(has_line(output, "pub mod array {"));
+        assert!(!output.contains("pub fn Rectdv("));
+        assert!(!output.contains("pub fn Color4fv(v: &["));
+    }
+
+    #[test]
+    fn test_glx_protocol() {
+        let output = generate(filter("1.0", "core", []), &Options { glx_protocol: true, ..options() });
+        // these are the statements that protocol.rs checks against the fixtures
+        let expected = [
+            "pub fn GetError(major_opcode: u8, context_tag: u32) -> ~[u8] {",
+            "let mut bytes: ~[u8] = ~[major_opcode, 115, 0, 0];",
+            "put(&mut bytes, context_tag as u64, 4);",
+            "finish_request(bytes)",
+            "}",
+            "pub unsafe fn Color3fv(v: *GLfloat) -> ~[u8] {",
+            "let mut bytes: ~[u8] = ~[0, 0, 0, 0];",
+            "for i in range(0, 3 as int) {",
+            "put(&mut bytes, unsafe { cast::transmute::<f32, u32>(*ptr::offset(v, i)) } as u64, 4);",
+            "}",
+            "finish_render(bytes, 8)",
+            "}",
+        ];
+        let lines = output.line_iter().map(|l| l.trim()).to_owned_vec();
+        let start = lines.iter().position(|l| *l == expected[0]).unwrap();
+        assert_eq!(lines.slice(start, start + expected.len()), expected.as_slice());
+        // without an opcode
+        assert!(!output.contains("pub fn Color3f("));
+        // sends the client arrays
+        assert!(!output.contains("pub fn DrawArrays("));
+        // the runtime is written without its tests
+        assert!(has_line(output, "fn finish_render(mut bytes: ~[u8], opcode: u16) -> ~[u8] {"));
+        assert!(!output.contains("include_bin!"));
+
+        let xml = "<registry><commands namespace=\"GL\">\
+            <command><proto>void <name>glTranslated</name></proto>\
+                <param><ptype>GLdouble</ptype> <name>x</name></param>\
+                <param><ptype>GLdouble</ptype> <name>y</name></param>\
+                <param><ptype>GLdouble</ptype> <name>z</name></param>\
+                <glx type=\"render\" opcode=\"189\"/></command>\
+            <command><proto>void <name>glTexGend</name></proto>\
+                <param><ptype>GLenum</ptype> <name>coord</name></param>\
+                <param><ptype>GLenum</ptype> <name>pname</name></param>\
+                <param><ptype>GLdouble</ptype> <name>param</name></param>\
+                <glx type=\"render\" opcode=\"115\"/></command>\
+            </commands></registry>";
+        let reg = Registry::from_xml(xml, Gl, None);
+        let output = io::with_str_writer(|w| Generator::write(w, &reg, Gl, &Options { glx_protocol: true, ..options() }));
+        assert!(has_line(output, "put(&mut bytes, unsafe { cast::transmute::<f64, u64>(x) }, 8);"));
+        assert!(has_line(output, "finish_render(bytes, 189)"));
+        // sent as param, coord, pname
+        assert!(!output.contains("pub fn TexGend("));
+    }
+
+    #[test]
+    fn test_optional_modules() {
+        let output = generate(filter("3.2", "core", []), &options());
+        for module in ["pub mod mock {", "pub mod capture {", "pub mod stats {", "pub mod state {"].iter() {
+            assert!(!has_line(output, *module));
+        }
+
+        let output = generate(filter("3.2", "core", []), &Options {
+            mock: true, capture: true, stats: true, ..options()
+        });
+        for module in ["pub mod mock {", "pub mod capture {", "pub mod stats {"].iter() {
+            assert!(has_line(output, *module));
+        }
+    }
+
+    #[test]
+    fn test_snake_case() {
+        assert_eq!(to_snake_case("DrawArrays"), ~"draw_arrays");
+        assert_eq!(to_snake_case("GetIntegerv"), ~"get_integerv");
+        assert_eq!(to_snake_case("TexImage2D"), ~"tex_image2d");
+        assert_eq!(to_snake_case("DebugMessageCallbackARB"), ~"debug_message_callback_arb");
+        assert_eq!(to_snake_case("VDPAUInitNV"), ~"vdpau_init_nv");
+        assert_eq!(escape_keyword("type"), ~"type_");
+        assert_eq!(escape_keyword("yield"), ~"yield_");
+        assert_eq!(escape_keyword("mode"), ~"mode");
+    }
+
+    #[test]
+    fn test_naming() {
+        let output = generate(filter("3.2", "core", []), &Options { naming: CNames, ..options() });
+        assert!(has_line(output, "pub static GL_TRIANGLES: GLenum = 0x0004;"));
+        assert!(has_line(output, "pub static GL_2D: GLenum = 0x0600;"));
+        assert!(output.contains("pub fn glDrawArrays(mode: GLenum, first: GLint, count: GLsizei)"));
+        assert!(!output.contains("pub fn DrawArrays("));
+
+        let output = generate(filter("3.2", "core", []), &Options {
+            naming: SnakeCaseNames, errors: true, ..options()
+        });
+        assert!(has_line(output, "pub static TRIANGLES: GLenum = 0x0004;"));
+        assert!(has_line(output, "pub static _2D: GLenum = 0x0600;"));
+        assert!(output.contains("pub fn draw_arrays(mode: GLenum, first: GLint, count: GLsizei)"));
+        // `GetError` keeps its name, so the typed wrapper is renamed
+        assert_eq!(output.line_iter().filter(|l| l.contains("pub fn get_error(")).to_owned_vec().len(), 1);
+        assert!(has_line(output, "pub fn check_error() -> Result<(), GlError> {"));
+        assert!(has_line(output, "match GlError::from_glenum(get_error()) {"));
+        assert!(has_line(output, "match check_error() {"));
+    }
+
+    #[test]
+    #[should_fail]
+    fn test_naming_helper_collision() {
+        let xml = "<registry><commands namespace=\"GL\">\
+            <command><proto>void <name>glunload</name></proto></command>\
+            </commands></registry>";
+        let reg = Registry::from_xml(xml, Gl, None);
+        io::with_str_writer(|w| Generator::write(w, &reg, Gl, &options()));
+    }
+
+    #[test]
+    fn test_capture() {
+        let xml = "<registry><commands namespace=\"GL\">\
+            <command><proto>void <name>glUniform4fv</name></proto>\
+                <param><ptype>GLint</ptype> <name>location</name></param>\
+                <param><ptype>GLsizei</ptype> <name>count</name></param>\
+                <param len=\"count*4\">const <ptype>GLfloat</ptype> *<name>value</name></param></command>\
+            <command><proto>void <name>glShaderSource</name></proto>\
+                <param><ptype>GLuint</ptype> <name>shader</name></param>\
+                <param><ptype>GLsizei</ptype> <name>count</name></param>\
+                <param len=\"count\">const <ptype>GLchar</ptype> *const*<name>string</name></param>\
+                <param len=\"count\">const <ptype>GLint</ptype> *<name>length</name></param></command>\
+            <command><proto>void <name>glDrawElements</name></proto>\
+                <param><ptype>GLenum</ptype> <name>mode</name></param>\
+                <param><ptype>GLsizei</ptype> <name>count</name></param>\
+                <param><ptype>GLenum</ptype> <name>type</name></param>\
+                <param len=\"COMPSIZE(count,type)\">const void *<name>indices</name></param></command>\
+            <command><proto>void <name>glDeleteSync</name></proto>\
+                <param><ptype>GLsync</ptype> <name>sync</name></param></command>\
+            </commands></registry>";
+        let reg = Registry::from_xml(xml, Gl, None);
+        let output = io::with_str_writer(|w| Generator::write(w, &reg, Gl, &Options { capture: true, ..options() }));
+        assert!(has_line(output, "write_bytes(capture_writer, value as *u8, ((count as uint * 4) as uint) * sys::size_of::<GLfloat>());"));
+        assert!(has_line(output, "write_c_strs(capture_writer, string as **c_char, length, count as uint);"));
+        assert!(has_line(output, "let id = ids[reader.read_le_u16() as uint];"));
+        assert!(!output.contains("read_le_u64() as uint as"));
+
+        // the arguments of pointers of unknown length and handles are not
+        // recorded, but calls to them are still written as skipped records
+        let lines = output.line_iter().map(|l| l.trim()).to_owned_vec();
+        let unrecorded = lines.iter().position(|l| l.starts_with("pub static UNRECORDED")).unwrap();
+        assert_eq!(lines.slice(unrecorded + 1, unrecorded + 4),
+                   ["\"glDrawElements\",", "\"glDeleteSync\",", "];"].as_slice());
+        let stub = lines.iter().position(|l| {
+            l.starts_with("pub extern \"C\" fn DrawElements(mode: GLenum, count: GLsizei, type_: GLenum, indices: *c_void)")
+        }).unwrap();
+        assert_eq!(lines.slice(stub + 4, stub + 6),
+                   ["capture_writer.write_le_u16(SKIPPED);", "capture_writer.write_le_u16(2);"].as_slice());
+        assert!(has_line(output, "SKIPPED => skipped.push(NAMES[ids[reader.read_le_u16() as uint]]),"));
+        assert!(!has_line(output, "2 => {"));
+    }
+
+    #[test]
+    #[should_fail]
+    fn test_naming_collision() {
+        let xml = "<registry><commands namespace=\"GL\">\
+            <command><proto>void <name>glVertexAttrib1d</name></proto></command>\
+            <command><proto>void <name>glVertexAttrib1D</name></proto></command>\
+            </commands></registry>";
+        let reg = Registry::from_xml(xml, Gl, None);
+        io::with_str_writer(|w| Generator::write(w, &reg, Gl, &Options { naming: SnakeCaseNames, ..options() }));
+    }
+
+    #[test]
+    fn test_select_backend() {
+        let backends = Backends::builtin();
+        assert_eq!(backends.names(), ~[~"global", ~"struct", ~"header"]);
+
+        let reg = Registry::from_xml(FIXTURE, Gl, filter("3.2", "core", []));
+        let backend = backends.select("global", &options()).unwrap();
+        let output = io::with_str_writer(|w| backend.write(w, &reg, Gl));
+        assert_eq!(output, generate(filter("3.2", "core", []), &options()));
+
+        let backend = backends.select("header", &options()).unwrap();
+        let output = io::with_str_writer(|w| backend.write(w, &reg, Gl));
+        assert!(has_line(output, "#define GL_TRIANGLES 0x0004"));
+
+        assert!(backends.select("unknown", &options()).is_none());
+    }
+
+    struct NamesBackend;
+
+    impl Backend for NamesBackend {
+        fn write(&self, writer: @Writer, reg: &Registry, _: Ns) {
+            for cmd in reg.cmds.iter() {
+                writer.write_line(cmd.proto.ident);
+            }
+        }
+    }
+
+    #[test]
+    fn test_register_backend() {
+        let mut backends = Backends::builtin();
+        backends.register("names", |_| ~NamesBackend as ~Backend);
+        assert_eq!(backends.names(), ~[~"global", ~"struct", ~"header", ~"names"]);
+
+        let reg = Registry::from_xml(FIXTURE, Gl, filter("3.2", "core", []));
+        let backend = backends.select("names", &options()).unwrap();
+        let output = io::with_str_writer(|w| backend.write(w, &reg, Gl));
+        assert!(has_line(output, "DrawArrays"));
+    }
+
+    #[test]
+    fn test_struct_backend() {
+        let reg = Registry::from_xml(FIXTURE, Gl, filter("3.2", "core", []));
+        let backend = Backends::builtin().select("struct", &options()).unwrap();
+        let output = io::with_str_writer(|w| backend.write(w, &reg, Gl));
+        assert!(has_line(output, "pub struct Gl {"));
+        assert!(has_line(output, "DrawArrays: FnPtr<extern \"C\" fn(mode: GLenum, first: GLint, count: GLsizei)>,"));
+        assert!(has_line(output, "DrawArrays: FnPtr::new(loadfn(\"glDrawArrays\"), failing::DrawArrays),"));
+        assert!(has_line(output, "#[fixed_stack_segment] #[inline] pub fn DrawArrays(&self, mode: GLenum, first: GLint, count: GLsizei) \
+                                  { unsafe { (self.DrawArrays.f)(mode, first, count) } }"));
+        assert!(has_line(output, "#[fixed_stack_segment] #[inline] pub fn GetError(&self) -> GLenum \
+                                  { unsafe { (self.GetError.f)() } }"));
+        // no global pointers
+        assert!(!output.contains("mod storage"));
+        assert!(!output.contains("pub fn load_with(loadfn: &fn(symbol: &str) -> Option<extern \"C\" fn()>) -> LoadReport"));
+
+        let backend = Backends::builtin().select("struct", &Options { naming: SnakeCaseNames, ..options() }).unwrap();
+        let output = io::with_str_writer(|w| backend.write(w, &reg, Gl));
+        assert!(output.contains("pub fn draw_arrays(&self, mode: GLenum, first: GLint, count: GLsizei)"));
+    }
+
+    #[test]
+    fn test_backend_flags() {
+        assert_eq!(options().flags(), ~[]);
+        assert_eq!(Options { mock: true, naming: CNames, ..options() }.flags(), ~["--mock", "--naming"]);
+    }
+
+    #[test]
+    #[should_fail]
+    fn test_header_backend_flags() {
+        Backends::builtin().select("header", &Options { mock: true, ..options() });
+    }
+
+    #[test]
+    #[should_fail]
+    fn test_struct_backend_flags() {
+        Backends::builtin().select("struct", &Options { lazy: true, ..options() });
+    }
+
+    #[test]
+    #[should_fail]
+    fn test_register_backend_twice() {
+        let mut backends = Backends::builtin();
+        backends.register("header", |_| ~NamesBackend as ~Backend);
     }
 }
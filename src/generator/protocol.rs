@@ -0,0 +1,93 @@
+// Copyright 2013 The gl-rs developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// The functions below are written into the generated bindings as the start of
+// `gl::protocol`, and are shared by the encoders of each command.
+
+fn put(bytes: &mut ~[u8], value: u64, size: uint) {
+    for i in range(0, size) {
+        bytes.push((value >> (i * 8)) as u8);
+    }
+}
+
+fn pad(bytes: &mut ~[u8]) {
+    while bytes.len() % 4 != 0 {
+        bytes.push(0);
+    }
+}
+
+// Fills in the length in bytes and the opcode of a render command
+fn finish_render(mut bytes: ~[u8], opcode: u16) -> ~[u8] {
+    pad(&mut bytes);
+    let len = bytes.len();
+    if len > 0xFFFF { fail!("Render command too large for glXRender") }
+    bytes[0] = len as u8;
+    bytes[1] = (len >> 8) as u8;
+    bytes[2] = opcode as u8;
+    bytes[3] = (opcode >> 8) as u8;
+    bytes
+}
+
+// Fills in the length in four byte units of a request
+fn finish_request(mut bytes: ~[u8]) -> ~[u8] {
+    pad(&mut bytes);
+    let len = bytes.len() / 4;
+    bytes[2] = len as u8;
+    bytes[3] = (len >> 8) as u8;
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cast;
+    use super::{put, finish_render, finish_request};
+
+    // Each request is built with the statements that the generator emits for
+    // it (see `test_glx_protocol` in main.rs), and compared with the bytes laid
+    // out in the GLX protocol specification.
+
+    #[test]
+    fn test_render() {
+        // Color3fv(&[1.0, 0.5, 0.25])
+        let mut bytes: ~[u8] = ~[0, 0, 0, 0];
+        for v in [1.0f32, 0.5, 0.25].iter() {
+            put(&mut bytes, unsafe { cast::transmute::<f32, u32>(*v) } as u64, 4);
+        }
+        assert_eq!(finish_render(bytes, 8), include_bin!("fixtures/glx/color3fv.bin").to_owned());
+
+        // Translated(1.0, 2.0, 3.0)
+        let mut bytes: ~[u8] = ~[0, 0, 0, 0];
+        for v in [1.0f64, 2.0, 3.0].iter() {
+            put(&mut bytes, unsafe { cast::transmute::<f64, u64>(*v) }, 8);
+        }
+        assert_eq!(finish_render(bytes, 189), include_bin!("fixtures/glx/translated.bin").to_owned());
+    }
+
+    #[test]
+    fn test_render_padding() {
+        // EdgeFlag(1)
+        let mut bytes: ~[u8] = ~[0, 0, 0, 0];
+        put(&mut bytes, 1u8 as u64, 1);
+        assert_eq!(finish_render(bytes, 22), include_bin!("fixtures/glx/edgeflag.bin").to_owned());
+    }
+
+    #[test]
+    fn test_single() {
+        // GetError, with the GLX extension at major opcode 143 and context tag 1
+        let mut bytes: ~[u8] = ~[143, 115, 0, 0];
+        put(&mut bytes, 1u32 as u64, 4);
+        assert_eq!(finish_request(bytes), include_bin!("fixtures/glx/geterror.bin").to_owned());
+    }
+}
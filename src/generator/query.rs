@@ -0,0 +1,227 @@
+// Copyright 2013 The gl-rs developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+
+//! Answers questions about the registry: which features and extensions
+//! require a symbol, and which versions and extensions exist.
+
+use std::io::Writer;
+
+use header;
+use registry::*;
+
+/// The prefixes of command and enum names in the registry
+fn gen_prefixes(ns: Ns) -> (&'static str, &'static str) {
+    match ns {
+        Gl => ("gl", "GL_"),
+        Glx => ("glx", "GLX_"),
+        Wgl => ("wgl", "WGL_"),
+    }
+}
+
+fn mentions(enums: &[~str], commands: &[~str], name: &str) -> bool {
+    enums.iter().chain(commands.iter()).any(|x| x.as_slice() == name)
+}
+
+/// Writes the features and extensions that require the named item, and the
+/// features that remove it from a profile.
+fn write_requirements(writer: @Writer, reg: &Registry, name: &str) {
+    let mut required = ~[];
+    let mut removed = ~[];
+    for f in reg.features.iter() {
+        if f.requires.iter().any(|r| mentions(r.enums, r.commands, name)) {
+            required.push(format!("{} ({} {})", f.name, f.api, f.number));
+        }
+        for rem in f.removes.iter().filter(|r| mentions(r.enums, r.commands, name)) {
+            removed.push(format!("{} profile by {} ({} {})", rem.profile, f.name, f.api, f.number));
+        }
+    }
+    for e in reg.extensions.iter() {
+        if e.requires.iter().any(|r| mentions(r.enums, r.commands, name)) {
+            required.push(e.name.clone());
+        }
+    }
+    writer.write_line(format!("    required by: {}",
+                              if required.is_empty() { ~"nothing" } else { required.connect(", ") }));
+    for r in removed.iter() {
+        writer.write_line(format!("    removed from the {}", *r));
+    }
+}
+
+fn write_cmd(writer: @Writer, reg: &Registry, ns: Ns, cmd: &Cmd) {
+    let (cmd_prefix, _) = gen_prefixes(ns);
+    let name = cmd_prefix + cmd.proto.ident;
+    writer.write_line("command " + name);
+    writer.write_line(format!("    {} {}({})", cmd.proto.ty.trim(), name, header::gen_params(cmd)));
+    for p in cmd.params.iter() {
+        match p.group {
+            Some(ref group) => writer.write_line(format!("    {}: {}", p.ident, *group)),
+            None => (),
+        }
+    }
+    match cmd.alias {
+        Some(ref alias) => writer.write_line(format!("    alias of: {}", *alias)),
+        None => (),
+    }
+    let aliases = reg.cmds.iter()
+        .filter(|c| c.alias.as_ref().map_default(false, |a| *a == name))
+        .map(|c| cmd_prefix + c.proto.ident)
+        .to_owned_vec();
+    if !aliases.is_empty() {
+        writer.write_line(format!("    aliases: {}", aliases.connect(", ")));
+    }
+    write_requirements(writer, reg, name);
+}
+
+fn write_enum(writer: @Writer, reg: &Registry, ns: Ns, enm: &Enum) {
+    let (cmd_prefix, enum_prefix) = gen_prefixes(ns);
+    let name = enum_prefix + enm.ident;
+    writer.write_line(format!("enum {} = {}", name, enm.value));
+    let groups = reg.groups.iter()
+        .filter(|g| g.enums.contains(&name))
+        .map(|g| g.name.clone())
+        .to_owned_vec();
+    if !groups.is_empty() {
+        writer.write_line(format!("    groups: {}", groups.connect(", ")));
+    }
+    match enm.alias {
+        Some(ref alias) => writer.write_line(format!("    alias of: {}", *alias)),
+        None => (),
+    }
+    let aliases = reg.enums.iter()
+        .filter(|e| e.alias.as_ref().map_default(false, |a| *a == name))
+        .map(|e| enum_prefix + e.ident)
+        .to_owned_vec();
+    if !aliases.is_empty() {
+        writer.write_line(format!("    aliases: {}", aliases.connect(", ")));
+    }
+    write_requirements(writer, reg, name);
+    let mut accepted = ~[];
+    for c in reg.cmds.iter() {
+        for p in c.params.iter() {
+            match p.group {
+                Some(ref group) if groups.contains(group) => {
+                    accepted.push(format!("{}{} ({})", cmd_prefix, c.proto.ident, p.ident));
+                }
+                _ => (),
+            }
+        }
+    }
+    if !accepted.is_empty() {
+        writer.write_line(format!("    accepted by: {}", accepted.connect(", ")));
+    }
+}
+
+/// Describes the command or enum with the supplied name, which may be given
+/// with or without its prefix, such as `glDrawArrays` or `DrawArrays`. Fails
+/// if the registry has no such symbol.
+pub fn write_symbol(writer: @Writer, reg: &Registry, ns: Ns, name: &str) {
+    let (cmd_prefix, enum_prefix) = gen_prefixes(ns);
+    let cmd_ident = if name.starts_with(cmd_prefix) { name.slice_from(cmd_prefix.len()) } else { name };
+    let enum_ident = if name.starts_with(enum_prefix) { name.slice_from(enum_prefix.len()) } else { name };
+
+    let cmd = reg.cmds.iter().find(|c| c.proto.ident.as_slice() == cmd_ident);
+    let enm = reg.enums.iter().find(|e| e.ident.as_slice() == enum_ident);
+    match (cmd, enm) {
+        (None, None) => fail!("Error: {} is not in the registry", name),
+        (Some(cmd), None) => write_cmd(writer, reg, ns, cmd),
+        (None, Some(enm)) => write_enum(writer, reg, ns, enm),
+        (Some(cmd), Some(enm)) => {
+            write_cmd(writer, reg, ns, cmd);
+            write_enum(writer, reg, ns, enm);
+        }
+    }
+}
+
+/// Lists the API, number and name of each feature
+pub fn write_versions(writer: @Writer, reg: &Registry) {
+    for f in reg.features.iter() {
+        writer.write_line(format!("{} {} {}", f.api, f.number, f.name));
+    }
+}
+
+/// Lists the extensions that support the supplied API, or all of them
+pub fn write_extensions(writer: @Writer, reg: &Registry, api: Option<&str>) {
+    for e in reg.extensions.iter() {
+        if api.map_default(true, |api| e.supported.iter().any(|s| s.as_slice() == api)) {
+            writer.write_line(e.name);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use registry::*;
+    use std::io;
+
+    static FIXTURE: &'static str = include_str!("fixtures/registry.xml");
+
+    fn query(name: &str) -> ~[~str] {
+        let reg = Registry::from_xml(FIXTURE, Gl, None);
+        let output = io::with_str_writer(|w| super::write_symbol(w, &reg, Gl, name));
+        output.line_iter().map(|l| l.to_owned()).to_owned_vec()
+    }
+
+    #[test]
+    fn test_cmd() {
+        let expected = ~[
+            ~"command glDrawArrays",
+            ~"    void glDrawArrays(GLenum mode, GLint first, GLsizei count)",
+            ~"    mode: PrimitiveType",
+            ~"    aliases: glDrawArraysEXT",
+            ~"    required by: GL_VERSION_1_0 (gl 1.0), GL_ES_VERSION_2_0 (gles2 2.0)",
+        ];
+        assert_eq!(query("glDrawArrays"), expected.clone());
+        assert_eq!(query("DrawArrays"), expected);
+
+        let output = query("Color3fv");
+        assert!(output.contains(&~"    removed from the core profile by GL_VERSION_3_2 (gl 3.2)"));
+
+        let output = query("DrawArraysEXT");
+        assert!(output.contains(&~"    alias of: glDrawArrays"));
+        assert!(output.contains(&~"    required by: GL_EXT_vertex_array"));
+    }
+
+    #[test]
+    fn test_enum() {
+        assert_eq!(query("GL_TRIANGLES"), ~[
+            ~"enum GL_TRIANGLES = 0x0004",
+            ~"    groups: PrimitiveType",
+            ~"    required by: GL_VERSION_1_0 (gl 1.0), GL_ES_VERSION_2_0 (gles2 2.0)",
+            ~"    accepted by: glDrawArrays (mode), glDrawArraysEXT (mode)",
+        ]);
+        let output = query("DEBUG_OUTPUT_SYNCHRONOUS");
+        assert!(output.contains(&~"    aliases: GL_DEBUG_OUTPUT_SYNCHRONOUS_ARB"));
+    }
+
+    #[test]
+    #[should_fail]
+    fn test_missing_symbol() {
+        query("glBogus");
+    }
+
+    #[test]
+    fn test_lists() {
+        let reg = Registry::from_xml(FIXTURE, Gl, None);
+        let output = io::with_str_writer(|w| super::write_versions(w, &reg));
+        assert_eq!(output.line_iter().to_owned_vec(),
+                   ~["gl 1.0 GL_VERSION_1_0", "gl 1.5 GL_VERSION_1_5", "gl 3.2 GL_VERSION_3_2",
+                     "gl 4.3 GL_VERSION_4_3", "gles2 2.0 GL_ES_VERSION_2_0"]);
+        let output = io::with_str_writer(|w| super::write_extensions(w, &reg, Some("gl")));
+        assert_eq!(output.line_iter().to_owned_vec(), ~["GL_ARB_debug_output", "GL_EXT_vertex_array"]);
+        let output = io::with_str_writer(|w| super::write_extensions(w, &reg, None));
+        assert_eq!(output.line_iter().count(), 3);
+    }
+}
@@ -62,18 +62,38 @@ fn trim_cmd_prefix<'a>(ident: &'a str, ns: Ns) -> &'a str {
     }
 }
 
+/// Returns true if the commands have the same return and parameter types
+fn same_signature(a: &Cmd, b: &Cmd) -> bool {
+    a.proto.ty == b.proto.ty
+        && a.params.len() == b.params.len()
+        && a.params.iter().zip(b.params.iter()).all(|(x, y)| x.ty == y.ty)
+}
+
 pub struct Registry {
     groups: ~[Group],
     enums: ~[Enum],
     cmds: ~[Cmd],
     features: ~[Feature],
     extensions: ~[Extension],
+    /// The features of the filtered API that are newer than the filtered
+    /// version, kept so that their removals can be reported. This is empty if
+    /// the registry was not filtered.
+    later_features: ~[Feature],
 }
 
 impl Registry {
     /// Generate a registry from the supplied XML string
     pub fn from_xml(data: &str, ns: Ns, filter: Option<Filter>) -> Registry {
-        RegistryBuilder::parse(data, ns, filter)
+        RegistryBuilder::parse(data, [], ns, filter)
+    }
+
+    /// Generate a registry from the supplied XML string, merged with the named
+    /// overlays before it is filtered, so that their extensions can be
+    /// selected like any other. Fails if an overlay redefines an enum or
+    /// command differently, or defines a feature or extension again.
+    pub fn from_xml_with_overlays(data: &str, overlays: &[(&str, &str)], ns: Ns,
+                                  filter: Option<Filter>) -> Registry {
+        RegistryBuilder::parse(data, overlays, ns, filter)
     }
 
     /// Returns a set of all the types used in the supplied registry. This is useful
@@ -121,6 +141,9 @@ pub struct Binding {
     ident: ~str,
     ty: ~str,
     group: Option<~str>,
+    /// The number of elements pointed to, either as a literal, the name of
+    /// another parameter, or an expression such as `COMPSIZE(format,type)`
+    len: Option<~str>,
 }
 
 pub struct Cmd {
@@ -178,7 +201,6 @@ pub struct GlxOpcode {
 
 struct RegistryBuilder {
     ns: Ns,
-    filter: Option<Filter>,
     port: SaxPort,
 }
 
@@ -191,12 +213,92 @@ pub struct Filter {
 
 /// A big, ugly, imperative impl with methods that accumulates a Registry struct
 impl<'self> RegistryBuilder {
-    fn parse(data: &str, ns: Ns, filter: Option<Filter>) -> Registry {
-        RegistryBuilder {
+    fn parse(data: &str, overlays: &[(&str, &str)], ns: Ns, filter: Option<Filter>) -> Registry {
+        let mut registry = RegistryBuilder {
             ns: ns,
-            filter: filter,
             port: parse_xml(data),
-        }.consume_registry()
+        }.consume_registry();
+
+        for &(name, overlay) in overlays.iter() {
+            let overlay = RegistryBuilder {
+                ns: ns,
+                port: parse_xml(overlay),
+            }.consume_registry();
+            RegistryBuilder::merge(&mut registry, overlay, name);
+        }
+
+        match filter {
+            Some(ref filter) => RegistryBuilder::filter(registry, filter),
+            None => registry,
+        }
+    }
+
+    /// Adds the groups, enums, commands, features and extensions of an overlay
+    /// to the registry. Enums and commands that are already defined are
+    /// skipped if they are identical, and anything else that is defined twice
+    /// is reported as a conflict.
+    fn merge(registry: &mut Registry, overlay: Registry, name: &str) {
+        let Registry { groups, enums, cmds, features, extensions, later_features: _ } = overlay;
+        let mut conflicts = ~[];
+
+        for group in groups.move_iter() {
+            match registry.groups.mut_iter().find(|g| g.name == group.name) {
+                Some(existing) => {
+                    for e in group.enums.move_iter() {
+                        if !existing.enums.contains(&e) { existing.enums.push(e) }
+                    }
+                    continue;
+                }
+                None => (),
+            }
+            registry.groups.push(group);
+        }
+
+        for e in enums.move_iter() {
+            match registry.enums.iter().find(|x| x.ident == e.ident) {
+                Some(x) if x.value == e.value => continue,
+                Some(x) => {
+                    conflicts.push(format!("enum {} is {} in the registry, but {} in the overlay",
+                                           e.ident, x.value, e.value));
+                    continue;
+                }
+                None => (),
+            }
+            registry.enums.push(e);
+        }
+
+        for c in cmds.move_iter() {
+            match registry.cmds.iter().find(|x| x.proto.ident == c.proto.ident) {
+                Some(x) if same_signature(x, &c) => continue,
+                Some(_) => {
+                    conflicts.push(format!("command {} has a different signature in the overlay",
+                                           c.proto.ident));
+                    continue;
+                }
+                None => (),
+            }
+            registry.cmds.push(c);
+        }
+
+        for f in features.move_iter() {
+            if registry.features.iter().any(|x| x.api == f.api && x.name == f.name) {
+                conflicts.push(format!("feature {} is already defined", f.name));
+            } else {
+                registry.features.push(f);
+            }
+        }
+
+        for ext in extensions.move_iter() {
+            if registry.extensions.iter().any(|x| x.name == ext.name) {
+                conflicts.push(format!("extension {} is already defined", ext.name));
+            } else {
+                registry.extensions.push(ext);
+            }
+        }
+
+        if !conflicts.is_empty() {
+            fail!("Conflicts merging {}:\n    {}", name, conflicts.connect("\n    "));
+        }
     }
 
     fn recv(&self) -> ParseEvent {
@@ -252,6 +354,7 @@ impl<'self> RegistryBuilder {
             cmds: ~[],
             features: ~[],
             extensions: ~[],
+            later_features: ~[],
         };
 
         loop {
@@ -311,75 +414,88 @@ impl<'self> RegistryBuilder {
             }
         }
 
-        match self.filter {
-            Some(ref filter) => {
-                let Registry {
-                    groups, enums, cmds, features: feats, extensions: exts
-                } = registry;
-
-                let mut desired_enums = HashSet::new();
-                let mut desired_cmds = HashSet::new();
+        registry
+    }
 
-                // find the features we want
-                let mut found_feat = false;
-                for f in feats.iter() {
-                    // XXX: verify that the string comparison with <= actually works as desired
-                    if f.api == filter.api && f.number <= filter.version {
-                        for req in f.requires.iter() {
-                            desired_enums.extend(&mut req.enums.iter().map(|x| x.clone()));
-                            desired_cmds.extend(&mut req.commands.iter().map(|x| x.clone()));
-                        }
-                    }
-                    if f.number == filter.version {
-                        found_feat = true;
-                    }
+    /// Keeps the parts of the registry that are required by the filter
+    fn filter(registry: Registry, filter: &Filter) -> Registry {
+        let Registry {
+            groups, enums, cmds, features: feats, extensions: exts, later_features: _
+        } = registry;
+
+        let mut desired_enums = HashSet::new();
+        let mut desired_cmds = HashSet::new();
+
+        // find the features we want
+        let mut found_feat = false;
+        for f in feats.iter() {
+            // XXX: verify that the string comparison with <= actually works as desired
+            if f.api == filter.api && f.number <= filter.version {
+                for req in f.requires.iter() {
+                    desired_enums.extend(&mut req.enums.iter().map(|x| x.clone()));
+                    desired_cmds.extend(&mut req.commands.iter().map(|x| x.clone()));
                 }
+            }
+            if f.number == filter.version {
+                found_feat = true;
+            }
+        }
 
-                // remove the things that should be removed
-                for f in feats.iter() {
-                    // XXX: verify that the string comparison with <= actually works as desired
-                    if f.api == filter.api && f.number <= filter.version {
-                        for rem in f.removes.iter() {
-                            if rem.profile == filter.profile {
-                                for enm in rem.enums.iter() {
-                                    debug2!("Removing {:?}", enm);
-                                    desired_enums.remove(enm);
-                                }
-                                for cmd in rem.commands.iter() {
-                                    debug2!("Removing {:?}", cmd);
-                                    desired_cmds.remove(cmd);
-                                }
-                            }
+        // remove the things that should be removed
+        for f in feats.iter() {
+            // XXX: verify that the string comparison with <= actually works as desired
+            if f.api == filter.api && f.number <= filter.version {
+                for rem in f.removes.iter() {
+                    if rem.profile == filter.profile {
+                        for enm in rem.enums.iter() {
+                            debug2!("Removing {:?}", enm);
+                            desired_enums.remove(enm);
+                        }
+                        for cmd in rem.commands.iter() {
+                            debug2!("Removing {:?}", cmd);
+                            desired_cmds.remove(cmd);
                         }
                     }
                 }
+            }
+        }
 
-                if !found_feat {
-                    fail2!("Did not find version {} in the registry", filter.version);
-                }
+        if !found_feat {
+            fail2!("Did not find version {} in the registry", filter.version);
+        }
 
-                for ext in exts.iter() {
-                    if filter.extensions.iter().any(|x| x == &ext.name) {
-                        if !ext.supported.iter().any(|x| x == &filter.api) {
-                            fail2!("Requested {}, which doesn't support the {} API", ext.name, filter.api);
-                        }
-                        for req in ext.requires.iter() {
-                            desired_enums.extend(&mut req.enums.iter().map(|x| x.clone()));
-                            desired_cmds.extend(&mut req.commands.iter().map(|x| x.clone()));
-                        }
-                    }
+        for ext in exts.iter() {
+            if filter.extensions.iter().any(|x| x == &ext.name) {
+                if !ext.supported.iter().any(|x| x == &filter.api) {
+                    fail2!("Requested {}, which doesn't support the {} API", ext.name, filter.api);
                 }
-
-                Registry {
-                    groups: groups,
-                    enums: enums.move_iter().filter(|e| desired_enums.contains(&(~"GL_" + e.ident))).to_owned_vec(),
-                    cmds: cmds.move_iter().filter(|c| desired_cmds.contains(&(~"gl" + c.proto.ident))).to_owned_vec(),
-                    // these aren't important after this step
-                    features: ~[],
-                    extensions: ~[],
+                for req in ext.requires.iter() {
+                    desired_enums.extend(&mut req.enums.iter().map(|x| x.clone()));
+                    desired_cmds.extend(&mut req.commands.iter().map(|x| x.clone()));
                 }
-            },
-            None => registry
+            }
+        }
+
+        // keep the features that were selected, and the later ones of the same
+        // api for their removals
+        let mut features = ~[];
+        let mut later_features = ~[];
+        for f in feats.move_iter().filter(|f| f.api == filter.api) {
+            if f.number <= filter.version {
+                features.push(f);
+            } else {
+                later_features.push(f);
+            }
+        }
+
+        Registry {
+            groups: groups,
+            enums: enums.move_iter().filter(|e| desired_enums.contains(&(~"GL_" + e.ident))).to_owned_vec(),
+            cmds: cmds.move_iter().filter(|c| desired_cmds.contains(&(~"gl" + c.proto.ident))).to_owned_vec(),
+            features: features,
+            // keep the extensions that were selected
+            extensions: exts.move_iter().filter(|e| filter.extensions.contains(&e.name)).to_owned_vec(),
+            later_features: later_features,
         }
     }
 
@@ -499,7 +615,7 @@ impl<'self> RegistryBuilder {
     fn consume_cmd(&self) -> Cmd {
         // consume command prototype
         let proto_atts = self.expect_start_element("proto");
-        let mut proto = self.consume_binding(proto_atts.find_clone("group"));
+        let mut proto = self.consume_binding(proto_atts.find_clone("group"), None);
         proto.ident = trim_cmd_prefix(proto.ident, self.ns).to_owned();
         self.expect_end_element("proto");
 
@@ -511,16 +627,16 @@ impl<'self> RegistryBuilder {
             match self.recv() {
                 StartElement(~"param", ref atts) => {
                     params.push(
-                        self.consume_binding(atts.find_clone("group"))
+                        self.consume_binding(atts.find_clone("group"), atts.find_clone("len"))
                     );
                     self.expect_end_element("param");
                 }
                 StartElement(~"alias", ref atts) => {
-                    alias = atts.find_clone("alias");
+                    alias = atts.find_clone("name");
                     self.expect_end_element("alias");
                 }
                 StartElement(~"vecequiv", ref atts) => {
-                    vecequiv = atts.find_clone("vecequiv");
+                    vecequiv = atts.find_clone("name");
                     self.expect_end_element("vecequiv");
                 }
                 StartElement(~"glx", ref atts) => {
@@ -548,7 +664,7 @@ impl<'self> RegistryBuilder {
         }
     }
 
-    fn consume_binding(&self, group: Option<~str>) -> Binding {
+    fn consume_binding(&self, group: Option<~str>, len: Option<~str>) -> Binding {
         // consume type
         let mut ty = ~"";
         loop {
@@ -567,6 +683,7 @@ impl<'self> RegistryBuilder {
             ident: ident,
             ty: ty,
             group: group,
+            len: len,
         }
     }
 }
@@ -654,3 +771,221 @@ impl FromXML for ~str {
         a.get_clone("name")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static FIXTURE: &'static str = include_str!("fixtures/registry.xml");
+
+    fn filter(api: &str, version: &str, profile: &str, extensions: &[&str]) -> Option<Filter> {
+        Some(Filter {
+            extensions: extensions.iter().map(|s| s.to_owned()).to_owned_vec(),
+            profile: profile.to_owned(),
+            version: version.to_owned(),
+            api: api.to_owned(),
+        })
+    }
+
+    fn enum_idents(reg: &Registry) -> ~[~str] {
+        reg.enums.iter().map(|e| e.ident.clone()).to_owned_vec()
+    }
+
+    fn cmd_idents(reg: &Registry) -> ~[~str] {
+        reg.cmds.iter().map(|c| c.proto.ident.clone()).to_owned_vec()
+    }
+
+    #[test]
+    fn test_groups() {
+        let reg = Registry::from_xml(FIXTURE, Gl, None);
+        assert_eq!(reg.groups.len(), 2);
+        assert_eq!(reg.groups[0].name, ~"PrimitiveType");
+        assert_eq!(reg.groups[0].enums, ~[~"GL_POINTS", ~"GL_TRIANGLES"]);
+        assert_eq!(reg.groups[1].name, ~"ErrorCode");
+    }
+
+    #[test]
+    fn test_enums() {
+        let reg = Registry::from_xml(FIXTURE, Gl, None);
+        assert_eq!(enum_idents(&reg), ~[~"POINTS", ~"TRIANGLES", ~"NO_ERROR", ~"INVALID_ENUM",
+                                         ~"2D", ~"CURRENT_COLOR", ~"DEBUG_OUTPUT_SYNCHRONOUS",
+                                         ~"DEBUG_OUTPUT_SYNCHRONOUS_ARB"]);
+        assert_eq!(reg.enums[1].value, ~"0x0004");
+        assert_eq!(reg.enums[1].alias, None);
+        assert_eq!(reg.enums[7].value, ~"0x8242");
+        assert_eq!(reg.enums[7].alias, Some(~"GL_DEBUG_OUTPUT_SYNCHRONOUS"));
+    }
+
+    #[test]
+    fn test_cmds() {
+        let reg = Registry::from_xml(FIXTURE, Gl, None);
+        assert_eq!(cmd_idents(&reg), ~[~"DrawArrays", ~"DrawArraysEXT", ~"GetError",
+                                       ~"Color3f", ~"Color3fv", ~"BufferData"]);
+
+        let draw_arrays = &reg.cmds[0];
+        assert_eq!(draw_arrays.proto.ty, ~"void ");
+        assert_eq!(draw_arrays.params.map(|p| p.ident.clone()), ~[~"mode", ~"first", ~"count"]);
+        assert_eq!(draw_arrays.params.map(|p| p.ty.clone()), ~[~"GLenum", ~"GLint", ~"GLsizei"]);
+        assert_eq!(draw_arrays.params[0].group, Some(~"PrimitiveType"));
+        assert_eq!(draw_arrays.params[1].group, None);
+        assert!(draw_arrays.is_safe);
+        let glx = draw_arrays.glx.get_ref();
+        assert_eq!(glx.ty, ~"render");
+        assert_eq!(glx.opcode, ~"193");
+
+        assert_eq!(reg.cmds[1].alias, Some(~"glDrawArrays"));
+        assert_eq!(reg.cmds[2].proto.ty, ~"GLenum");
+        assert_eq!(reg.cmds[2].proto.group, Some(~"ErrorCode"));
+        assert!(reg.cmds[2].params.is_empty());
+        assert_eq!(reg.cmds[3].vecequiv, Some(~"glColor3fv"));
+
+        let color3fv = &reg.cmds[4];
+        assert_eq!(color3fv.params[0].ty, ~"const GLfloat *");
+        assert_eq!(color3fv.params[0].len, Some(~"3"));
+        assert!(!color3fv.is_safe);
+
+        let buffer_data = &reg.cmds[5];
+        assert_eq!(buffer_data.params[2].ty, ~"const void *");
+        assert_eq!(buffer_data.params[2].len, Some(~"size"));
+        assert!(buffer_data.glx.is_none());
+    }
+
+    #[test]
+    fn test_features() {
+        let reg = Registry::from_xml(FIXTURE, Gl, None);
+        assert_eq!(reg.features.map(|f| f.number.clone()), ~[~"1.0", ~"1.5", ~"3.2", ~"4.3", ~"2.0"]);
+
+        // the <type> in the first <require> is skipped
+        let gl10 = &reg.features[0];
+        assert_eq!(gl10.api, ~"gl");
+        assert_eq!(gl10.name, ~"GL_VERSION_1_0");
+        assert_eq!(gl10.requires.len(), 1);
+        assert_eq!(gl10.requires[0].enums.len(), 6);
+        assert_eq!(gl10.requires[0].commands, ~[~"glDrawArrays", ~"glGetError", ~"glColor3f", ~"glColor3fv"]);
+        assert!(gl10.removes.is_empty());
+
+        assert_eq!(reg.features[1].requires[0].comment, Some(~"Buffer objects"));
+
+        let gl32 = &reg.features[2];
+        assert!(gl32.requires.is_empty());
+        assert_eq!(gl32.removes.len(), 1);
+        assert_eq!(gl32.removes[0].profile, ~"core");
+        assert_eq!(gl32.removes[0].enums, ~[~"GL_CURRENT_COLOR"]);
+        assert_eq!(gl32.removes[0].commands, ~[~"glColor3f", ~"glColor3fv"]);
+
+        assert_eq!(reg.features[4].api, ~"gles2");
+    }
+
+    #[test]
+    fn test_extensions() {
+        let reg = Registry::from_xml(FIXTURE, Gl, None);
+        assert_eq!(reg.extensions.map(|e| e.name.clone()),
+                   ~[~"GL_ARB_debug_output", ~"GL_EXT_vertex_array", ~"GL_OES_texture_3D"]);
+        assert_eq!(reg.extensions[0].supported, ~[~"gl", ~"glcore"]);
+        assert_eq!(reg.extensions[1].requires[0].commands, ~[~"glDrawArraysEXT"]);
+        assert_eq!(reg.extensions[2].supported, ~[~"gles2"]);
+    }
+
+    #[test]
+    fn test_filter_version() {
+        let reg = Registry::from_xml(FIXTURE, Gl, filter("gl", "1.0", "core", []));
+        assert_eq!(cmd_idents(&reg), ~[~"DrawArrays", ~"GetError", ~"Color3f", ~"Color3fv"]);
+        assert_eq!(reg.features.map(|f| f.number.clone()), ~[~"1.0"]);
+        assert_eq!(reg.later_features.map(|f| f.number.clone()), ~[~"1.5", ~"3.2", ~"4.3"]);
+        assert!(reg.extensions.is_empty());
+
+        let reg = Registry::from_xml(FIXTURE, Gl, filter("gl", "4.3", "compatibility", []));
+        assert!(enum_idents(&reg).contains(&~"DEBUG_OUTPUT_SYNCHRONOUS"));
+        assert!(cmd_idents(&reg).contains(&~"BufferData"));
+    }
+
+    #[test]
+    fn test_filter_profile() {
+        let core = Registry::from_xml(FIXTURE, Gl, filter("gl", "3.2", "core", []));
+        assert_eq!(cmd_idents(&core), ~[~"DrawArrays", ~"GetError", ~"BufferData"]);
+        assert!(!enum_idents(&core).contains(&~"CURRENT_COLOR"));
+
+        let compat = Registry::from_xml(FIXTURE, Gl, filter("gl", "3.2", "compatibility", []));
+        assert_eq!(cmd_idents(&compat), ~[~"DrawArrays", ~"GetError", ~"Color3f", ~"Color3fv", ~"BufferData"]);
+        assert!(enum_idents(&compat).contains(&~"CURRENT_COLOR"));
+    }
+
+    #[test]
+    fn test_filter_api() {
+        let reg = Registry::from_xml(FIXTURE, Gl, filter("gles2", "2.0", "core", []));
+        assert_eq!(enum_idents(&reg), ~[~"TRIANGLES"]);
+        assert_eq!(cmd_idents(&reg), ~[~"DrawArrays"]);
+    }
+
+    #[test]
+    fn test_filter_extensions() {
+        let reg = Registry::from_xml(FIXTURE, Gl, filter("gl", "1.0", "core",
+                                                         ["GL_ARB_debug_output", "GL_EXT_vertex_array"]));
+        assert!(enum_idents(&reg).contains(&~"DEBUG_OUTPUT_SYNCHRONOUS_ARB"));
+        assert!(cmd_idents(&reg).contains(&~"DrawArraysEXT"));
+        assert_eq!(reg.extensions.map(|e| e.name.clone()), ~[~"GL_ARB_debug_output", ~"GL_EXT_vertex_array"]);
+    }
+
+    #[test]
+    #[should_fail]
+    fn test_filter_unsupported_extension() {
+        Registry::from_xml(FIXTURE, Gl, filter("gl", "3.2", "core", ["GL_OES_texture_3D"]));
+    }
+
+    #[test]
+    #[should_fail]
+    fn test_filter_missing_version() {
+        Registry::from_xml(FIXTURE, Gl, filter("gl", "9.9", "core", []));
+    }
+
+    #[test]
+    #[should_fail]
+    fn test_unexpected_element() {
+        Registry::from_xml("<registry><bogus/></registry>", Gl, None);
+    }
+
+    static OVERLAY: &'static str = include_str!("fixtures/overlay.xml");
+
+    #[test]
+    fn test_overlay() {
+        let reg = Registry::from_xml_with_overlays(FIXTURE, [("overlay.xml", OVERLAY)], Gl, None);
+        assert_eq!(enum_idents(&reg).iter().filter(|e| e.as_slice() == "TRIANGLES").count(), 1);
+        assert!(enum_idents(&reg).contains(&~"PATCHES_VENDOR"));
+        assert!(cmd_idents(&reg).contains(&~"PatchCountVENDOR"));
+        assert_eq!(reg.groups[0].enums, ~[~"GL_POINTS", ~"GL_TRIANGLES", ~"GL_PATCHES_VENDOR"]);
+
+        // the overlay's extension can be selected like any other
+        let reg = Registry::from_xml_with_overlays(FIXTURE, [("overlay.xml", OVERLAY)], Gl,
+                                                   filter("gl", "3.2", "core", ["GL_VENDOR_patches"]));
+        assert!(enum_idents(&reg).contains(&~"PATCHES_VENDOR"));
+        assert!(cmd_idents(&reg).contains(&~"PatchCountVENDOR"));
+        assert_eq!(reg.extensions.map(|e| e.name.clone()), ~[~"GL_VENDOR_patches"]);
+    }
+
+    #[test]
+    #[should_fail]
+    fn test_overlay_enum_conflict() {
+        let overlay = "<registry><enums namespace=\"GL\">\
+                       <enum value=\"0x0005\" name=\"GL_TRIANGLES\"/>\
+                       </enums></registry>";
+        Registry::from_xml_with_overlays(FIXTURE, [("overlay.xml", overlay)], Gl, None);
+    }
+
+    #[test]
+    #[should_fail]
+    fn test_overlay_cmd_conflict() {
+        let overlay = "<registry><commands namespace=\"GL\"><command>\
+                       <proto>void <name>glGetError</name></proto>\
+                       </command></commands></registry>";
+        Registry::from_xml_with_overlays(FIXTURE, [("overlay.xml", overlay)], Gl, None);
+    }
+
+    #[test]
+    #[should_fail]
+    fn test_overlay_extension_conflict() {
+        let overlay = "<registry><extensions>\
+                       <extension name=\"GL_EXT_vertex_array\" supported=\"gl\"/>\
+                       </extensions></registry>";
+        Registry::from_xml_with_overlays(FIXTURE, [("overlay.xml", overlay)], Gl, None);
+    }
+}
@@ -0,0 +1,263 @@
+// Copyright 2013 The gl-rs developers. For a full listing of the authors,
+// refer to the AUTHORS file at the top-level directory of this distribution.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// The kind of value returned for a state variable, which determines the
+/// `Get*v` function used to query it.
+#[deriving(Clone, Eq)]
+pub enum StateTy {
+    /// Queried with `GetBooleanv`
+    Boolean,
+    /// A capability, queried with `GetBooleanv` and named `*_enabled`
+    Capability,
+    /// Queried with `GetIntegerv`
+    Integer,
+    /// Queried with `GetInteger64v`
+    Integer64,
+    /// Queried with `GetFloatv`
+    Float,
+}
+
+impl StateTy {
+    /// The command used to query values of this type
+    pub fn query_cmd(&self) -> &'static str {
+        match *self {
+            Boolean | Capability => "GetBooleanv",
+            Integer => "GetIntegerv",
+            Integer64 => "GetInteger64v",
+            Float => "GetFloatv",
+        }
+    }
+
+    /// The type written to by the query command
+    pub fn gl_ty(&self) -> &'static str {
+        match *self {
+            Boolean | Capability => "GLboolean",
+            Integer => "GLint",
+            Integer64 => "GLint64",
+            Float => "GLfloat",
+        }
+    }
+
+    /// The type returned by the generated getter, for a single element
+    pub fn rust_ty(&self) -> &'static str {
+        match *self {
+            Boolean | Capability => "bool",
+            Integer => "GLint",
+            Integer64 => "GLint64",
+            Float => "GLfloat",
+        }
+    }
+
+    /// The zero value used to initialise the query buffer
+    pub fn zero(&self) -> &'static str {
+        match *self {
+            Boolean | Capability => "::FALSE",
+            Integer | Integer64 => "0",
+            Float => "0.0",
+        }
+    }
+}
+
+/// Returns `true` if the state variable is an implementation-dependent limit
+/// that should be included in the generated `Limits` struct.
+pub fn is_limit(pname: &str) -> bool {
+    pname.starts_with("MAX_") || pname.starts_with("MIN_")
+}
+
+/// Looks up the type and element count of a `GetPName` state variable. The
+/// registry doesn't record this information, so it is taken from the state
+/// tables in the OpenGL 4.3 specification. Returns `None` for state variables
+/// that are not yet covered.
+pub fn pname_info(pname: &str) -> Option<(StateTy, uint)> {
+    match pname {
+        // capabilities
+        "BLEND"                                     => Some((Capability, 1)),
+        "COLOR_LOGIC_OP"                            => Some((Capability, 1)),
+        "CULL_FACE"                                 => Some((Capability, 1)),
+        "DEPTH_TEST"                                => Some((Capability, 1)),
+        "DITHER"                                    => Some((Capability, 1)),
+        "LINE_SMOOTH"                               => Some((Capability, 1)),
+        "POLYGON_OFFSET_FILL"                       => Some((Capability, 1)),
+        "POLYGON_OFFSET_LINE"                       => Some((Capability, 1)),
+        "POLYGON_OFFSET_POINT"                      => Some((Capability, 1)),
+        "POLYGON_SMOOTH"                            => Some((Capability, 1)),
+        "PROGRAM_POINT_SIZE"                        => Some((Capability, 1)),
+        "SCISSOR_TEST"                              => Some((Capability, 1)),
+        "STENCIL_TEST"                              => Some((Capability, 1)),
+
+        // booleans
+        "COLOR_WRITEMASK"                           => Some((Boolean, 4)),
+        "DEPTH_WRITEMASK"                           => Some((Boolean, 1)),
+        "DOUBLEBUFFER"                              => Some((Boolean, 1)),
+        "PACK_LSB_FIRST"                            => Some((Boolean, 1)),
+        "PACK_SWAP_BYTES"                           => Some((Boolean, 1)),
+        "SHADER_COMPILER"                           => Some((Boolean, 1)),
+        "STEREO"                                    => Some((Boolean, 1)),
+        "UNPACK_LSB_FIRST"                          => Some((Boolean, 1)),
+        "UNPACK_SWAP_BYTES"                         => Some((Boolean, 1)),
+
+        // integers
+        "ACTIVE_TEXTURE"                            => Some((Integer, 1)),
+        "ARRAY_BUFFER_BINDING"                      => Some((Integer, 1)),
+        "BLEND_DST_ALPHA"                           => Some((Integer, 1)),
+        "BLEND_DST_RGB"                             => Some((Integer, 1)),
+        "BLEND_EQUATION_ALPHA"                      => Some((Integer, 1)),
+        "BLEND_EQUATION_RGB"                        => Some((Integer, 1)),
+        "BLEND_SRC_ALPHA"                           => Some((Integer, 1)),
+        "BLEND_SRC_RGB"                             => Some((Integer, 1)),
+        "CONTEXT_FLAGS"                             => Some((Integer, 1)),
+        "CONTEXT_PROFILE_MASK"                      => Some((Integer, 1)),
+        "CULL_FACE_MODE"                            => Some((Integer, 1)),
+        "CURRENT_PROGRAM"                           => Some((Integer, 1)),
+        "DEPTH_FUNC"                                => Some((Integer, 1)),
+        "DRAW_BUFFER"                               => Some((Integer, 1)),
+        "DRAW_FRAMEBUFFER_BINDING"                  => Some((Integer, 1)),
+        "ELEMENT_ARRAY_BUFFER_BINDING"              => Some((Integer, 1)),
+        "FRONT_FACE"                                => Some((Integer, 1)),
+        "LOGIC_OP_MODE"                             => Some((Integer, 1)),
+        "MAJOR_VERSION"                             => Some((Integer, 1)),
+        "MINOR_VERSION"                             => Some((Integer, 1)),
+        "NUM_COMPRESSED_TEXTURE_FORMATS"            => Some((Integer, 1)),
+        "NUM_EXTENSIONS"                            => Some((Integer, 1)),
+        "NUM_PROGRAM_BINARY_FORMATS"                => Some((Integer, 1)),
+        "NUM_SHADER_BINARY_FORMATS"                 => Some((Integer, 1)),
+        "PACK_ALIGNMENT"                            => Some((Integer, 1)),
+        "PACK_ROW_LENGTH"                           => Some((Integer, 1)),
+        "PACK_SKIP_PIXELS"                          => Some((Integer, 1)),
+        "PACK_SKIP_ROWS"                            => Some((Integer, 1)),
+        "PIXEL_PACK_BUFFER_BINDING"                 => Some((Integer, 1)),
+        "PIXEL_UNPACK_BUFFER_BINDING"               => Some((Integer, 1)),
+        "POLYGON_MODE"                              => Some((Integer, 2)),
+        "PROVOKING_VERTEX"                          => Some((Integer, 1)),
+        "READ_BUFFER"                               => Some((Integer, 1)),
+        "READ_FRAMEBUFFER_BINDING"                  => Some((Integer, 1)),
+        "RENDERBUFFER_BINDING"                      => Some((Integer, 1)),
+        "SAMPLE_BUFFERS"                            => Some((Integer, 1)),
+        "SAMPLES"                                   => Some((Integer, 1)),
+        "SAMPLER_BINDING"                           => Some((Integer, 1)),
+        "SCISSOR_BOX"                               => Some((Integer, 4)),
+        "STENCIL_BACK_FAIL"                         => Some((Integer, 1)),
+        "STENCIL_BACK_FUNC"                         => Some((Integer, 1)),
+        "STENCIL_BACK_REF"                          => Some((Integer, 1)),
+        "STENCIL_BACK_VALUE_MASK"                   => Some((Integer, 1)),
+        "STENCIL_BACK_WRITEMASK"                    => Some((Integer, 1)),
+        "STENCIL_CLEAR_VALUE"                       => Some((Integer, 1)),
+        "STENCIL_FAIL"                              => Some((Integer, 1)),
+        "STENCIL_FUNC"                              => Some((Integer, 1)),
+        "STENCIL_REF"                               => Some((Integer, 1)),
+        "STENCIL_VALUE_MASK"                        => Some((Integer, 1)),
+        "STENCIL_WRITEMASK"                         => Some((Integer, 1)),
+        "SUBPIXEL_BITS"                             => Some((Integer, 1)),
+        "TEXTURE_BINDING_1D"                        => Some((Integer, 1)),
+        "TEXTURE_BINDING_1D_ARRAY"                  => Some((Integer, 1)),
+        "TEXTURE_BINDING_2D"                        => Some((Integer, 1)),
+        "TEXTURE_BINDING_2D_ARRAY"                  => Some((Integer, 1)),
+        "TEXTURE_BINDING_2D_MULTISAMPLE"            => Some((Integer, 1)),
+        "TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY"      => Some((Integer, 1)),
+        "TEXTURE_BINDING_3D"                        => Some((Integer, 1)),
+        "TEXTURE_BINDING_BUFFER"                    => Some((Integer, 1)),
+        "TEXTURE_BINDING_CUBE_MAP"                  => Some((Integer, 1)),
+        "TEXTURE_BINDING_RECTANGLE"                 => Some((Integer, 1)),
+        "UNIFORM_BUFFER_BINDING"                    => Some((Integer, 1)),
+        "UNPACK_ALIGNMENT"                          => Some((Integer, 1)),
+        "UNPACK_IMAGE_HEIGHT"                       => Some((Integer, 1)),
+        "UNPACK_ROW_LENGTH"                         => Some((Integer, 1)),
+        "UNPACK_SKIP_IMAGES"                        => Some((Integer, 1)),
+        "UNPACK_SKIP_PIXELS"                        => Some((Integer, 1)),
+        "UNPACK_SKIP_ROWS"                          => Some((Integer, 1)),
+        "VERTEX_ARRAY_BINDING"                      => Some((Integer, 1)),
+        "VIEWPORT"                                  => Some((Integer, 4)),
+
+        // integer limits
+        "MAX_3D_TEXTURE_SIZE"                       => Some((Integer, 1)),
+        "MAX_ARRAY_TEXTURE_LAYERS"                  => Some((Integer, 1)),
+        "MAX_CLIP_DISTANCES"                        => Some((Integer, 1)),
+        "MAX_COLOR_ATTACHMENTS"                     => Some((Integer, 1)),
+        "MAX_COLOR_TEXTURE_SAMPLES"                 => Some((Integer, 1)),
+        "MAX_COMBINED_FRAGMENT_UNIFORM_COMPONENTS"  => Some((Integer, 1)),
+        "MAX_COMBINED_GEOMETRY_UNIFORM_COMPONENTS"  => Some((Integer, 1)),
+        "MAX_COMBINED_TEXTURE_IMAGE_UNITS"          => Some((Integer, 1)),
+        "MAX_COMBINED_UNIFORM_BLOCKS"               => Some((Integer, 1)),
+        "MAX_COMBINED_VERTEX_UNIFORM_COMPONENTS"    => Some((Integer, 1)),
+        "MAX_COMPUTE_SHARED_MEMORY_SIZE"            => Some((Integer, 1)),
+        "MAX_COMPUTE_UNIFORM_BLOCKS"                => Some((Integer, 1)),
+        "MAX_COMPUTE_WORK_GROUP_INVOCATIONS"        => Some((Integer, 1)),
+        "MAX_CUBE_MAP_TEXTURE_SIZE"                 => Some((Integer, 1)),
+        "MAX_DEBUG_MESSAGE_LENGTH"                  => Some((Integer, 1)),
+        "MAX_DEPTH_TEXTURE_SAMPLES"                 => Some((Integer, 1)),
+        "MAX_DRAW_BUFFERS"                          => Some((Integer, 1)),
+        "MAX_DUAL_SOURCE_DRAW_BUFFERS"              => Some((Integer, 1)),
+        "MAX_ELEMENTS_INDICES"                      => Some((Integer, 1)),
+        "MAX_ELEMENTS_VERTICES"                     => Some((Integer, 1)),
+        "MAX_FRAGMENT_INPUT_COMPONENTS"             => Some((Integer, 1)),
+        "MAX_FRAGMENT_UNIFORM_BLOCKS"               => Some((Integer, 1)),
+        "MAX_FRAGMENT_UNIFORM_COMPONENTS"           => Some((Integer, 1)),
+        "MAX_FRAGMENT_UNIFORM_VECTORS"              => Some((Integer, 1)),
+        "MAX_FRAMEBUFFER_HEIGHT"                    => Some((Integer, 1)),
+        "MAX_FRAMEBUFFER_LAYERS"                    => Some((Integer, 1)),
+        "MAX_FRAMEBUFFER_SAMPLES"                   => Some((Integer, 1)),
+        "MAX_FRAMEBUFFER_WIDTH"                     => Some((Integer, 1)),
+        "MAX_GEOMETRY_INPUT_COMPONENTS"             => Some((Integer, 1)),
+        "MAX_GEOMETRY_OUTPUT_COMPONENTS"            => Some((Integer, 1)),
+        "MAX_GEOMETRY_TEXTURE_IMAGE_UNITS"          => Some((Integer, 1)),
+        "MAX_GEOMETRY_UNIFORM_BLOCKS"               => Some((Integer, 1)),
+        "MAX_GEOMETRY_UNIFORM_COMPONENTS"           => Some((Integer, 1)),
+        "MAX_INTEGER_SAMPLES"                       => Some((Integer, 1)),
+        "MAX_LABEL_LENGTH"                          => Some((Integer, 1)),
+        "MAX_PROGRAM_TEXEL_OFFSET"                  => Some((Integer, 1)),
+        "MAX_RECTANGLE_TEXTURE_SIZE"                => Some((Integer, 1)),
+        "MAX_RENDERBUFFER_SIZE"                     => Some((Integer, 1)),
+        "MAX_SAMPLE_MASK_WORDS"                     => Some((Integer, 1)),
+        "MAX_TEXTURE_BUFFER_SIZE"                   => Some((Integer, 1)),
+        "MAX_TEXTURE_IMAGE_UNITS"                   => Some((Integer, 1)),
+        "MAX_TEXTURE_SIZE"                          => Some((Integer, 1)),
+        "MAX_UNIFORM_BLOCK_SIZE"                    => Some((Integer, 1)),
+        "MAX_UNIFORM_BUFFER_BINDINGS"               => Some((Integer, 1)),
+        "MAX_UNIFORM_LOCATIONS"                     => Some((Integer, 1)),
+        "MAX_VARYING_COMPONENTS"                    => Some((Integer, 1)),
+        "MAX_VARYING_VECTORS"                       => Some((Integer, 1)),
+        "MAX_VERTEX_ATTRIBS"                        => Some((Integer, 1)),
+        "MAX_VERTEX_OUTPUT_COMPONENTS"              => Some((Integer, 1)),
+        "MAX_VERTEX_TEXTURE_IMAGE_UNITS"            => Some((Integer, 1)),
+        "MAX_VERTEX_UNIFORM_BLOCKS"                 => Some((Integer, 1)),
+        "MAX_VERTEX_UNIFORM_COMPONENTS"             => Some((Integer, 1)),
+        "MAX_VERTEX_UNIFORM_VECTORS"                => Some((Integer, 1)),
+        "MAX_VIEWPORTS"                             => Some((Integer, 1)),
+        "MAX_VIEWPORT_DIMS"                         => Some((Integer, 2)),
+        "MIN_PROGRAM_TEXEL_OFFSET"                  => Some((Integer, 1)),
+
+        // 64-bit integers
+        "MAX_ELEMENT_INDEX"                         => Some((Integer64, 1)),
+        "MAX_SERVER_WAIT_TIMEOUT"                   => Some((Integer64, 1)),
+        "TIMESTAMP"                                 => Some((Integer64, 1)),
+
+        // floats
+        "ALIASED_LINE_WIDTH_RANGE"                  => Some((Float, 2)),
+        "BLEND_COLOR"                               => Some((Float, 4)),
+        "COLOR_CLEAR_VALUE"                         => Some((Float, 4)),
+        "DEPTH_CLEAR_VALUE"                         => Some((Float, 1)),
+        "DEPTH_RANGE"                               => Some((Float, 2)),
+        "LINE_WIDTH"                                => Some((Float, 1)),
+        "MAX_TEXTURE_LOD_BIAS"                      => Some((Float, 1)),
+        "POINT_SIZE"                                => Some((Float, 1)),
+        "POINT_SIZE_RANGE"                          => Some((Float, 2)),
+        "POLYGON_OFFSET_FACTOR"                     => Some((Float, 1)),
+        "POLYGON_OFFSET_UNITS"                      => Some((Float, 1)),
+        "SMOOTH_LINE_WIDTH_RANGE"                   => Some((Float, 2)),
+        "VIEWPORT_BOUNDS_RANGE"                     => Some((Float, 2)),
+
+        _ => None,
+    }
+}
@@ -2527,9 +2527,15 @@ macro_rules! fn_mod(
             pub fn is_loaded() -> bool { unsafe { ::storage::$name.is_loaded } }
             
             #[inline]
-            pub fn load_with(loadfn: &fn(symbol: &str) -> Option<extern "C" fn()>) {
-                unsafe { ::storage::$name = ::FnPtr::new(loadfn($sym), ::failing::$name) }
+            pub fn load_with(loadfn: &fn(symbol: &str) -> Option<extern "C" fn()>) -> bool {
+                unsafe {
+                    ::storage::$name = ::FnPtr::new(loadfn($sym), ::failing::$name);
+                    ::storage::$name.is_loaded
+                }
             }
+            
+            #[inline]
+            pub fn unload() { unsafe { ::storage::$name = ::FnPtr { f: ::failing::$name, is_loaded: false } } }
         }
     )
 )
@@ -3674,575 +3680,1179 @@ mod failing {
     failing!(fn WaitSync(GLsync, GLbitfield, GLuint64))
 }
 
+/// The symbols that were found and missing during a call to `load_with`
+pub struct LoadReport {
+    /// The feature level that the bindings were generated for
+    requested: &'static str,
+    loaded: ~[&'static str],
+    missing: ~[&'static str],
+}
+
+impl LoadReport {
+    fn record(&mut self, symbol: &'static str, is_loaded: bool) {
+        if is_loaded { self.loaded.push(symbol) } else { self.missing.push(symbol) }
+    }
+
+    /// Returns `true` if every symbol was loaded
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+impl ToStr for LoadReport {
+    fn to_str(&self) -> ~str {
+        if self.is_complete() {
+            format!("{} requested, all {} commands loaded",
+                    self.requested, self.loaded.len())
+        } else {
+            format!("{} requested, {} commands missing: {}",
+                    self.requested, self.missing.len(), self.missing.connect(", "))
+        }
+    }
+}
+
 /// Load each OpenGL symbol using a custom load function. This allows for the
 /// use of functions like `glfwGetProcAddress` or `SDL_GL_GetProcAddress`.
+/// Returns a report of the symbols that could not be loaded.
 ///
 /// ~~~
-/// let gl = gl::load_with(glfw::get_proc_address);
+/// let report = gl::load_with(glfw::get_proc_address);
+/// if !report.is_complete() { fail!(report.to_str()) }
 /// ~~~
-pub fn load_with(loadfn: &fn(symbol: &str) -> Option<extern "C" fn()>) {
-    ActiveShaderProgram::load_with(|s| loadfn(s));
-    ActiveTexture::load_with(|s| loadfn(s));
-    AttachShader::load_with(|s| loadfn(s));
-    BeginConditionalRender::load_with(|s| loadfn(s));
-    BeginQuery::load_with(|s| loadfn(s));
-    BeginQueryIndexed::load_with(|s| loadfn(s));
-    BeginTransformFeedback::load_with(|s| loadfn(s));
-    BindAttribLocation::load_with(|s| loadfn(s));
-    BindBuffer::load_with(|s| loadfn(s));
-    BindBufferBase::load_with(|s| loadfn(s));
-    BindBufferRange::load_with(|s| loadfn(s));
-    BindFragDataLocation::load_with(|s| loadfn(s));
-    BindFragDataLocationIndexed::load_with(|s| loadfn(s));
-    BindFramebuffer::load_with(|s| loadfn(s));
-    BindImageTexture::load_with(|s| loadfn(s));
-    BindProgramPipeline::load_with(|s| loadfn(s));
-    BindRenderbuffer::load_with(|s| loadfn(s));
-    BindSampler::load_with(|s| loadfn(s));
-    BindTexture::load_with(|s| loadfn(s));
-    BindTransformFeedback::load_with(|s| loadfn(s));
-    BindVertexArray::load_with(|s| loadfn(s));
-    BindVertexBuffer::load_with(|s| loadfn(s));
-    BlendColor::load_with(|s| loadfn(s));
-    BlendEquation::load_with(|s| loadfn(s));
-    BlendEquationSeparate::load_with(|s| loadfn(s));
-    BlendEquationSeparatei::load_with(|s| loadfn(s));
-    BlendEquationi::load_with(|s| loadfn(s));
-    BlendFunc::load_with(|s| loadfn(s));
-    BlendFuncSeparate::load_with(|s| loadfn(s));
-    BlendFuncSeparatei::load_with(|s| loadfn(s));
-    BlendFunci::load_with(|s| loadfn(s));
-    BlitFramebuffer::load_with(|s| loadfn(s));
-    BufferData::load_with(|s| loadfn(s));
-    BufferSubData::load_with(|s| loadfn(s));
-    CheckFramebufferStatus::load_with(|s| loadfn(s));
-    ClampColor::load_with(|s| loadfn(s));
-    Clear::load_with(|s| loadfn(s));
-    ClearBufferData::load_with(|s| loadfn(s));
-    ClearBufferSubData::load_with(|s| loadfn(s));
-    ClearBufferfi::load_with(|s| loadfn(s));
-    ClearBufferfv::load_with(|s| loadfn(s));
-    ClearBufferiv::load_with(|s| loadfn(s));
-    ClearBufferuiv::load_with(|s| loadfn(s));
-    ClearColor::load_with(|s| loadfn(s));
-    ClearDepth::load_with(|s| loadfn(s));
-    ClearDepthf::load_with(|s| loadfn(s));
-    ClearStencil::load_with(|s| loadfn(s));
-    ClientWaitSync::load_with(|s| loadfn(s));
-    ColorMask::load_with(|s| loadfn(s));
-    ColorMaski::load_with(|s| loadfn(s));
-    ColorP3ui::load_with(|s| loadfn(s));
-    ColorP3uiv::load_with(|s| loadfn(s));
-    ColorP4ui::load_with(|s| loadfn(s));
-    ColorP4uiv::load_with(|s| loadfn(s));
-    CompileShader::load_with(|s| loadfn(s));
-    CompressedTexImage1D::load_with(|s| loadfn(s));
-    CompressedTexImage2D::load_with(|s| loadfn(s));
-    CompressedTexImage3D::load_with(|s| loadfn(s));
-    CompressedTexSubImage1D::load_with(|s| loadfn(s));
-    CompressedTexSubImage2D::load_with(|s| loadfn(s));
-    CompressedTexSubImage3D::load_with(|s| loadfn(s));
-    CopyBufferSubData::load_with(|s| loadfn(s));
-    CopyImageSubData::load_with(|s| loadfn(s));
-    CopyTexImage1D::load_with(|s| loadfn(s));
-    CopyTexImage2D::load_with(|s| loadfn(s));
-    CopyTexSubImage1D::load_with(|s| loadfn(s));
-    CopyTexSubImage2D::load_with(|s| loadfn(s));
-    CopyTexSubImage3D::load_with(|s| loadfn(s));
-    CreateProgram::load_with(|s| loadfn(s));
-    CreateShader::load_with(|s| loadfn(s));
-    CreateShaderProgramv::load_with(|s| loadfn(s));
-    CullFace::load_with(|s| loadfn(s));
-    DebugMessageCallback::load_with(|s| loadfn(s));
-    DebugMessageControl::load_with(|s| loadfn(s));
-    DebugMessageInsert::load_with(|s| loadfn(s));
-    DeleteBuffers::load_with(|s| loadfn(s));
-    DeleteFramebuffers::load_with(|s| loadfn(s));
-    DeleteProgram::load_with(|s| loadfn(s));
-    DeleteProgramPipelines::load_with(|s| loadfn(s));
-    DeleteQueries::load_with(|s| loadfn(s));
-    DeleteRenderbuffers::load_with(|s| loadfn(s));
-    DeleteSamplers::load_with(|s| loadfn(s));
-    DeleteShader::load_with(|s| loadfn(s));
-    DeleteSync::load_with(|s| loadfn(s));
-    DeleteTextures::load_with(|s| loadfn(s));
-    DeleteTransformFeedbacks::load_with(|s| loadfn(s));
-    DeleteVertexArrays::load_with(|s| loadfn(s));
-    DepthFunc::load_with(|s| loadfn(s));
-    DepthMask::load_with(|s| loadfn(s));
-    DepthRange::load_with(|s| loadfn(s));
-    DepthRangeArrayv::load_with(|s| loadfn(s));
-    DepthRangeIndexed::load_with(|s| loadfn(s));
-    DepthRangef::load_with(|s| loadfn(s));
-    DetachShader::load_with(|s| loadfn(s));
-    Disable::load_with(|s| loadfn(s));
-    DisableVertexAttribArray::load_with(|s| loadfn(s));
-    Disablei::load_with(|s| loadfn(s));
-    DispatchCompute::load_with(|s| loadfn(s));
-    DispatchComputeIndirect::load_with(|s| loadfn(s));
-    DrawArrays::load_with(|s| loadfn(s));
-    DrawArraysIndirect::load_with(|s| loadfn(s));
-    DrawArraysInstanced::load_with(|s| loadfn(s));
-    DrawArraysInstancedBaseInstance::load_with(|s| loadfn(s));
-    DrawBuffer::load_with(|s| loadfn(s));
-    DrawBuffers::load_with(|s| loadfn(s));
-    DrawElements::load_with(|s| loadfn(s));
-    DrawElementsBaseVertex::load_with(|s| loadfn(s));
-    DrawElementsIndirect::load_with(|s| loadfn(s));
-    DrawElementsInstanced::load_with(|s| loadfn(s));
-    DrawElementsInstancedBaseInstance::load_with(|s| loadfn(s));
-    DrawElementsInstancedBaseVertex::load_with(|s| loadfn(s));
-    DrawElementsInstancedBaseVertexBaseInstance::load_with(|s| loadfn(s));
-    DrawRangeElements::load_with(|s| loadfn(s));
-    DrawRangeElementsBaseVertex::load_with(|s| loadfn(s));
-    DrawTransformFeedback::load_with(|s| loadfn(s));
-    DrawTransformFeedbackInstanced::load_with(|s| loadfn(s));
-    DrawTransformFeedbackStream::load_with(|s| loadfn(s));
-    DrawTransformFeedbackStreamInstanced::load_with(|s| loadfn(s));
-    Enable::load_with(|s| loadfn(s));
-    EnableVertexAttribArray::load_with(|s| loadfn(s));
-    Enablei::load_with(|s| loadfn(s));
-    EndConditionalRender::load_with(|s| loadfn(s));
-    EndQuery::load_with(|s| loadfn(s));
-    EndQueryIndexed::load_with(|s| loadfn(s));
-    EndTransformFeedback::load_with(|s| loadfn(s));
-    FenceSync::load_with(|s| loadfn(s));
-    Finish::load_with(|s| loadfn(s));
-    Flush::load_with(|s| loadfn(s));
-    FlushMappedBufferRange::load_with(|s| loadfn(s));
-    FramebufferParameteri::load_with(|s| loadfn(s));
-    FramebufferRenderbuffer::load_with(|s| loadfn(s));
-    FramebufferTexture::load_with(|s| loadfn(s));
-    FramebufferTexture1D::load_with(|s| loadfn(s));
-    FramebufferTexture2D::load_with(|s| loadfn(s));
-    FramebufferTexture3D::load_with(|s| loadfn(s));
-    FramebufferTextureLayer::load_with(|s| loadfn(s));
-    FrontFace::load_with(|s| loadfn(s));
-    GenBuffers::load_with(|s| loadfn(s));
-    GenFramebuffers::load_with(|s| loadfn(s));
-    GenProgramPipelines::load_with(|s| loadfn(s));
-    GenQueries::load_with(|s| loadfn(s));
-    GenRenderbuffers::load_with(|s| loadfn(s));
-    GenSamplers::load_with(|s| loadfn(s));
-    GenTextures::load_with(|s| loadfn(s));
-    GenTransformFeedbacks::load_with(|s| loadfn(s));
-    GenVertexArrays::load_with(|s| loadfn(s));
-    GenerateMipmap::load_with(|s| loadfn(s));
-    GetActiveAtomicCounterBufferiv::load_with(|s| loadfn(s));
-    GetActiveAttrib::load_with(|s| loadfn(s));
-    GetActiveSubroutineName::load_with(|s| loadfn(s));
-    GetActiveSubroutineUniformName::load_with(|s| loadfn(s));
-    GetActiveSubroutineUniformiv::load_with(|s| loadfn(s));
-    GetActiveUniform::load_with(|s| loadfn(s));
-    GetActiveUniformBlockName::load_with(|s| loadfn(s));
-    GetActiveUniformBlockiv::load_with(|s| loadfn(s));
-    GetActiveUniformName::load_with(|s| loadfn(s));
-    GetActiveUniformsiv::load_with(|s| loadfn(s));
-    GetAttachedShaders::load_with(|s| loadfn(s));
-    GetAttribLocation::load_with(|s| loadfn(s));
-    GetBooleani_v::load_with(|s| loadfn(s));
-    GetBooleanv::load_with(|s| loadfn(s));
-    GetBufferParameteri64v::load_with(|s| loadfn(s));
-    GetBufferParameteriv::load_with(|s| loadfn(s));
-    GetBufferPointerv::load_with(|s| loadfn(s));
-    GetBufferSubData::load_with(|s| loadfn(s));
-    GetCompressedTexImage::load_with(|s| loadfn(s));
-    GetDebugMessageLog::load_with(|s| loadfn(s));
-    GetDoublei_v::load_with(|s| loadfn(s));
-    GetDoublev::load_with(|s| loadfn(s));
-    GetError::load_with(|s| loadfn(s));
-    GetFloati_v::load_with(|s| loadfn(s));
-    GetFloatv::load_with(|s| loadfn(s));
-    GetFragDataIndex::load_with(|s| loadfn(s));
-    GetFragDataLocation::load_with(|s| loadfn(s));
-    GetFramebufferAttachmentParameteriv::load_with(|s| loadfn(s));
-    GetFramebufferParameteriv::load_with(|s| loadfn(s));
-    GetInteger64i_v::load_with(|s| loadfn(s));
-    GetInteger64v::load_with(|s| loadfn(s));
-    GetIntegeri_v::load_with(|s| loadfn(s));
-    GetIntegerv::load_with(|s| loadfn(s));
-    GetInternalformati64v::load_with(|s| loadfn(s));
-    GetInternalformativ::load_with(|s| loadfn(s));
-    GetMultisamplefv::load_with(|s| loadfn(s));
-    GetObjectLabel::load_with(|s| loadfn(s));
-    GetObjectPtrLabel::load_with(|s| loadfn(s));
-    GetProgramBinary::load_with(|s| loadfn(s));
-    GetProgramInfoLog::load_with(|s| loadfn(s));
-    GetProgramInterfaceiv::load_with(|s| loadfn(s));
-    GetProgramPipelineInfoLog::load_with(|s| loadfn(s));
-    GetProgramPipelineiv::load_with(|s| loadfn(s));
-    GetProgramResourceIndex::load_with(|s| loadfn(s));
-    GetProgramResourceLocation::load_with(|s| loadfn(s));
-    GetProgramResourceLocationIndex::load_with(|s| loadfn(s));
-    GetProgramResourceName::load_with(|s| loadfn(s));
-    GetProgramResourceiv::load_with(|s| loadfn(s));
-    GetProgramStageiv::load_with(|s| loadfn(s));
-    GetProgramiv::load_with(|s| loadfn(s));
-    GetQueryIndexediv::load_with(|s| loadfn(s));
-    GetQueryObjecti64v::load_with(|s| loadfn(s));
-    GetQueryObjectiv::load_with(|s| loadfn(s));
-    GetQueryObjectui64v::load_with(|s| loadfn(s));
-    GetQueryObjectuiv::load_with(|s| loadfn(s));
-    GetQueryiv::load_with(|s| loadfn(s));
-    GetRenderbufferParameteriv::load_with(|s| loadfn(s));
-    GetSamplerParameterIiv::load_with(|s| loadfn(s));
-    GetSamplerParameterIuiv::load_with(|s| loadfn(s));
-    GetSamplerParameterfv::load_with(|s| loadfn(s));
-    GetSamplerParameteriv::load_with(|s| loadfn(s));
-    GetShaderInfoLog::load_with(|s| loadfn(s));
-    GetShaderPrecisionFormat::load_with(|s| loadfn(s));
-    GetShaderSource::load_with(|s| loadfn(s));
-    GetShaderiv::load_with(|s| loadfn(s));
-    GetString::load_with(|s| loadfn(s));
-    GetStringi::load_with(|s| loadfn(s));
-    GetSubroutineIndex::load_with(|s| loadfn(s));
-    GetSubroutineUniformLocation::load_with(|s| loadfn(s));
-    GetSynciv::load_with(|s| loadfn(s));
-    GetTexImage::load_with(|s| loadfn(s));
-    GetTexLevelParameterfv::load_with(|s| loadfn(s));
-    GetTexLevelParameteriv::load_with(|s| loadfn(s));
-    GetTexParameterIiv::load_with(|s| loadfn(s));
-    GetTexParameterIuiv::load_with(|s| loadfn(s));
-    GetTexParameterfv::load_with(|s| loadfn(s));
-    GetTexParameteriv::load_with(|s| loadfn(s));
-    GetTransformFeedbackVarying::load_with(|s| loadfn(s));
-    GetUniformBlockIndex::load_with(|s| loadfn(s));
-    GetUniformIndices::load_with(|s| loadfn(s));
-    GetUniformLocation::load_with(|s| loadfn(s));
-    GetUniformSubroutineuiv::load_with(|s| loadfn(s));
-    GetUniformdv::load_with(|s| loadfn(s));
-    GetUniformfv::load_with(|s| loadfn(s));
-    GetUniformiv::load_with(|s| loadfn(s));
-    GetUniformuiv::load_with(|s| loadfn(s));
-    GetVertexAttribIiv::load_with(|s| loadfn(s));
-    GetVertexAttribIuiv::load_with(|s| loadfn(s));
-    GetVertexAttribLdv::load_with(|s| loadfn(s));
-    GetVertexAttribPointerv::load_with(|s| loadfn(s));
-    GetVertexAttribdv::load_with(|s| loadfn(s));
-    GetVertexAttribfv::load_with(|s| loadfn(s));
-    GetVertexAttribiv::load_with(|s| loadfn(s));
-    Hint::load_with(|s| loadfn(s));
-    InvalidateBufferData::load_with(|s| loadfn(s));
-    InvalidateBufferSubData::load_with(|s| loadfn(s));
-    InvalidateFramebuffer::load_with(|s| loadfn(s));
-    InvalidateSubFramebuffer::load_with(|s| loadfn(s));
-    InvalidateTexImage::load_with(|s| loadfn(s));
-    InvalidateTexSubImage::load_with(|s| loadfn(s));
-    IsBuffer::load_with(|s| loadfn(s));
-    IsEnabled::load_with(|s| loadfn(s));
-    IsEnabledi::load_with(|s| loadfn(s));
-    IsFramebuffer::load_with(|s| loadfn(s));
-    IsProgram::load_with(|s| loadfn(s));
-    IsProgramPipeline::load_with(|s| loadfn(s));
-    IsQuery::load_with(|s| loadfn(s));
-    IsRenderbuffer::load_with(|s| loadfn(s));
-    IsSampler::load_with(|s| loadfn(s));
-    IsShader::load_with(|s| loadfn(s));
-    IsSync::load_with(|s| loadfn(s));
-    IsTexture::load_with(|s| loadfn(s));
-    IsTransformFeedback::load_with(|s| loadfn(s));
-    IsVertexArray::load_with(|s| loadfn(s));
-    LineWidth::load_with(|s| loadfn(s));
-    LinkProgram::load_with(|s| loadfn(s));
-    LogicOp::load_with(|s| loadfn(s));
-    MapBuffer::load_with(|s| loadfn(s));
-    MapBufferRange::load_with(|s| loadfn(s));
-    MemoryBarrier::load_with(|s| loadfn(s));
-    MinSampleShading::load_with(|s| loadfn(s));
-    MultiDrawArrays::load_with(|s| loadfn(s));
-    MultiDrawArraysIndirect::load_with(|s| loadfn(s));
-    MultiDrawElements::load_with(|s| loadfn(s));
-    MultiDrawElementsBaseVertex::load_with(|s| loadfn(s));
-    MultiDrawElementsIndirect::load_with(|s| loadfn(s));
-    MultiTexCoordP1ui::load_with(|s| loadfn(s));
-    MultiTexCoordP1uiv::load_with(|s| loadfn(s));
-    MultiTexCoordP2ui::load_with(|s| loadfn(s));
-    MultiTexCoordP2uiv::load_with(|s| loadfn(s));
-    MultiTexCoordP3ui::load_with(|s| loadfn(s));
-    MultiTexCoordP3uiv::load_with(|s| loadfn(s));
-    MultiTexCoordP4ui::load_with(|s| loadfn(s));
-    MultiTexCoordP4uiv::load_with(|s| loadfn(s));
-    NormalP3ui::load_with(|s| loadfn(s));
-    NormalP3uiv::load_with(|s| loadfn(s));
-    ObjectLabel::load_with(|s| loadfn(s));
-    ObjectPtrLabel::load_with(|s| loadfn(s));
-    PatchParameterfv::load_with(|s| loadfn(s));
-    PatchParameteri::load_with(|s| loadfn(s));
-    PauseTransformFeedback::load_with(|s| loadfn(s));
-    PixelStoref::load_with(|s| loadfn(s));
-    PixelStorei::load_with(|s| loadfn(s));
-    PointParameterf::load_with(|s| loadfn(s));
-    PointParameterfv::load_with(|s| loadfn(s));
-    PointParameteri::load_with(|s| loadfn(s));
-    PointParameteriv::load_with(|s| loadfn(s));
-    PointSize::load_with(|s| loadfn(s));
-    PolygonMode::load_with(|s| loadfn(s));
-    PolygonOffset::load_with(|s| loadfn(s));
-    PopDebugGroup::load_with(|s| loadfn(s));
-    PrimitiveRestartIndex::load_with(|s| loadfn(s));
-    ProgramBinary::load_with(|s| loadfn(s));
-    ProgramParameteri::load_with(|s| loadfn(s));
-    ProgramUniform1d::load_with(|s| loadfn(s));
-    ProgramUniform1dv::load_with(|s| loadfn(s));
-    ProgramUniform1f::load_with(|s| loadfn(s));
-    ProgramUniform1fv::load_with(|s| loadfn(s));
-    ProgramUniform1i::load_with(|s| loadfn(s));
-    ProgramUniform1iv::load_with(|s| loadfn(s));
-    ProgramUniform1ui::load_with(|s| loadfn(s));
-    ProgramUniform1uiv::load_with(|s| loadfn(s));
-    ProgramUniform2d::load_with(|s| loadfn(s));
-    ProgramUniform2dv::load_with(|s| loadfn(s));
-    ProgramUniform2f::load_with(|s| loadfn(s));
-    ProgramUniform2fv::load_with(|s| loadfn(s));
-    ProgramUniform2i::load_with(|s| loadfn(s));
-    ProgramUniform2iv::load_with(|s| loadfn(s));
-    ProgramUniform2ui::load_with(|s| loadfn(s));
-    ProgramUniform2uiv::load_with(|s| loadfn(s));
-    ProgramUniform3d::load_with(|s| loadfn(s));
-    ProgramUniform3dv::load_with(|s| loadfn(s));
-    ProgramUniform3f::load_with(|s| loadfn(s));
-    ProgramUniform3fv::load_with(|s| loadfn(s));
-    ProgramUniform3i::load_with(|s| loadfn(s));
-    ProgramUniform3iv::load_with(|s| loadfn(s));
-    ProgramUniform3ui::load_with(|s| loadfn(s));
-    ProgramUniform3uiv::load_with(|s| loadfn(s));
-    ProgramUniform4d::load_with(|s| loadfn(s));
-    ProgramUniform4dv::load_with(|s| loadfn(s));
-    ProgramUniform4f::load_with(|s| loadfn(s));
-    ProgramUniform4fv::load_with(|s| loadfn(s));
-    ProgramUniform4i::load_with(|s| loadfn(s));
-    ProgramUniform4iv::load_with(|s| loadfn(s));
-    ProgramUniform4ui::load_with(|s| loadfn(s));
-    ProgramUniform4uiv::load_with(|s| loadfn(s));
-    ProgramUniformMatrix2dv::load_with(|s| loadfn(s));
-    ProgramUniformMatrix2fv::load_with(|s| loadfn(s));
-    ProgramUniformMatrix2x3dv::load_with(|s| loadfn(s));
-    ProgramUniformMatrix2x3fv::load_with(|s| loadfn(s));
-    ProgramUniformMatrix2x4dv::load_with(|s| loadfn(s));
-    ProgramUniformMatrix2x4fv::load_with(|s| loadfn(s));
-    ProgramUniformMatrix3dv::load_with(|s| loadfn(s));
-    ProgramUniformMatrix3fv::load_with(|s| loadfn(s));
-    ProgramUniformMatrix3x2dv::load_with(|s| loadfn(s));
-    ProgramUniformMatrix3x2fv::load_with(|s| loadfn(s));
-    ProgramUniformMatrix3x4dv::load_with(|s| loadfn(s));
-    ProgramUniformMatrix3x4fv::load_with(|s| loadfn(s));
-    ProgramUniformMatrix4dv::load_with(|s| loadfn(s));
-    ProgramUniformMatrix4fv::load_with(|s| loadfn(s));
-    ProgramUniformMatrix4x2dv::load_with(|s| loadfn(s));
-    ProgramUniformMatrix4x2fv::load_with(|s| loadfn(s));
-    ProgramUniformMatrix4x3dv::load_with(|s| loadfn(s));
-    ProgramUniformMatrix4x3fv::load_with(|s| loadfn(s));
-    ProvokingVertex::load_with(|s| loadfn(s));
-    PushDebugGroup::load_with(|s| loadfn(s));
-    QueryCounter::load_with(|s| loadfn(s));
-    ReadBuffer::load_with(|s| loadfn(s));
-    ReadPixels::load_with(|s| loadfn(s));
-    ReleaseShaderCompiler::load_with(|s| loadfn(s));
-    RenderbufferStorage::load_with(|s| loadfn(s));
-    RenderbufferStorageMultisample::load_with(|s| loadfn(s));
-    ResumeTransformFeedback::load_with(|s| loadfn(s));
-    SampleCoverage::load_with(|s| loadfn(s));
-    SampleMaski::load_with(|s| loadfn(s));
-    SamplerParameterIiv::load_with(|s| loadfn(s));
-    SamplerParameterIuiv::load_with(|s| loadfn(s));
-    SamplerParameterf::load_with(|s| loadfn(s));
-    SamplerParameterfv::load_with(|s| loadfn(s));
-    SamplerParameteri::load_with(|s| loadfn(s));
-    SamplerParameteriv::load_with(|s| loadfn(s));
-    Scissor::load_with(|s| loadfn(s));
-    ScissorArrayv::load_with(|s| loadfn(s));
-    ScissorIndexed::load_with(|s| loadfn(s));
-    ScissorIndexedv::load_with(|s| loadfn(s));
-    SecondaryColorP3ui::load_with(|s| loadfn(s));
-    SecondaryColorP3uiv::load_with(|s| loadfn(s));
-    ShaderBinary::load_with(|s| loadfn(s));
-    ShaderSource::load_with(|s| loadfn(s));
-    ShaderStorageBlockBinding::load_with(|s| loadfn(s));
-    StencilFunc::load_with(|s| loadfn(s));
-    StencilFuncSeparate::load_with(|s| loadfn(s));
-    StencilMask::load_with(|s| loadfn(s));
-    StencilMaskSeparate::load_with(|s| loadfn(s));
-    StencilOp::load_with(|s| loadfn(s));
-    StencilOpSeparate::load_with(|s| loadfn(s));
-    TexBuffer::load_with(|s| loadfn(s));
-    TexBufferRange::load_with(|s| loadfn(s));
-    TexCoordP1ui::load_with(|s| loadfn(s));
-    TexCoordP1uiv::load_with(|s| loadfn(s));
-    TexCoordP2ui::load_with(|s| loadfn(s));
-    TexCoordP2uiv::load_with(|s| loadfn(s));
-    TexCoordP3ui::load_with(|s| loadfn(s));
-    TexCoordP3uiv::load_with(|s| loadfn(s));
-    TexCoordP4ui::load_with(|s| loadfn(s));
-    TexCoordP4uiv::load_with(|s| loadfn(s));
-    TexImage1D::load_with(|s| loadfn(s));
-    TexImage2D::load_with(|s| loadfn(s));
-    TexImage2DMultisample::load_with(|s| loadfn(s));
-    TexImage3D::load_with(|s| loadfn(s));
-    TexImage3DMultisample::load_with(|s| loadfn(s));
-    TexParameterIiv::load_with(|s| loadfn(s));
-    TexParameterIuiv::load_with(|s| loadfn(s));
-    TexParameterf::load_with(|s| loadfn(s));
-    TexParameterfv::load_with(|s| loadfn(s));
-    TexParameteri::load_with(|s| loadfn(s));
-    TexParameteriv::load_with(|s| loadfn(s));
-    TexStorage1D::load_with(|s| loadfn(s));
-    TexStorage2D::load_with(|s| loadfn(s));
-    TexStorage2DMultisample::load_with(|s| loadfn(s));
-    TexStorage3D::load_with(|s| loadfn(s));
-    TexStorage3DMultisample::load_with(|s| loadfn(s));
-    TexSubImage1D::load_with(|s| loadfn(s));
-    TexSubImage2D::load_with(|s| loadfn(s));
-    TexSubImage3D::load_with(|s| loadfn(s));
-    TextureView::load_with(|s| loadfn(s));
-    TransformFeedbackVaryings::load_with(|s| loadfn(s));
-    Uniform1d::load_with(|s| loadfn(s));
-    Uniform1dv::load_with(|s| loadfn(s));
-    Uniform1f::load_with(|s| loadfn(s));
-    Uniform1fv::load_with(|s| loadfn(s));
-    Uniform1i::load_with(|s| loadfn(s));
-    Uniform1iv::load_with(|s| loadfn(s));
-    Uniform1ui::load_with(|s| loadfn(s));
-    Uniform1uiv::load_with(|s| loadfn(s));
-    Uniform2d::load_with(|s| loadfn(s));
-    Uniform2dv::load_with(|s| loadfn(s));
-    Uniform2f::load_with(|s| loadfn(s));
-    Uniform2fv::load_with(|s| loadfn(s));
-    Uniform2i::load_with(|s| loadfn(s));
-    Uniform2iv::load_with(|s| loadfn(s));
-    Uniform2ui::load_with(|s| loadfn(s));
-    Uniform2uiv::load_with(|s| loadfn(s));
-    Uniform3d::load_with(|s| loadfn(s));
-    Uniform3dv::load_with(|s| loadfn(s));
-    Uniform3f::load_with(|s| loadfn(s));
-    Uniform3fv::load_with(|s| loadfn(s));
-    Uniform3i::load_with(|s| loadfn(s));
-    Uniform3iv::load_with(|s| loadfn(s));
-    Uniform3ui::load_with(|s| loadfn(s));
-    Uniform3uiv::load_with(|s| loadfn(s));
-    Uniform4d::load_with(|s| loadfn(s));
-    Uniform4dv::load_with(|s| loadfn(s));
-    Uniform4f::load_with(|s| loadfn(s));
-    Uniform4fv::load_with(|s| loadfn(s));
-    Uniform4i::load_with(|s| loadfn(s));
-    Uniform4iv::load_with(|s| loadfn(s));
-    Uniform4ui::load_with(|s| loadfn(s));
-    Uniform4uiv::load_with(|s| loadfn(s));
-    UniformBlockBinding::load_with(|s| loadfn(s));
-    UniformMatrix2dv::load_with(|s| loadfn(s));
-    UniformMatrix2fv::load_with(|s| loadfn(s));
-    UniformMatrix2x3dv::load_with(|s| loadfn(s));
-    UniformMatrix2x3fv::load_with(|s| loadfn(s));
-    UniformMatrix2x4dv::load_with(|s| loadfn(s));
-    UniformMatrix2x4fv::load_with(|s| loadfn(s));
-    UniformMatrix3dv::load_with(|s| loadfn(s));
-    UniformMatrix3fv::load_with(|s| loadfn(s));
-    UniformMatrix3x2dv::load_with(|s| loadfn(s));
-    UniformMatrix3x2fv::load_with(|s| loadfn(s));
-    UniformMatrix3x4dv::load_with(|s| loadfn(s));
-    UniformMatrix3x4fv::load_with(|s| loadfn(s));
-    UniformMatrix4dv::load_with(|s| loadfn(s));
-    UniformMatrix4fv::load_with(|s| loadfn(s));
-    UniformMatrix4x2dv::load_with(|s| loadfn(s));
-    UniformMatrix4x2fv::load_with(|s| loadfn(s));
-    UniformMatrix4x3dv::load_with(|s| loadfn(s));
-    UniformMatrix4x3fv::load_with(|s| loadfn(s));
-    UniformSubroutinesuiv::load_with(|s| loadfn(s));
-    UnmapBuffer::load_with(|s| loadfn(s));
-    UseProgram::load_with(|s| loadfn(s));
-    UseProgramStages::load_with(|s| loadfn(s));
-    ValidateProgram::load_with(|s| loadfn(s));
-    ValidateProgramPipeline::load_with(|s| loadfn(s));
-    VertexAttrib1d::load_with(|s| loadfn(s));
-    VertexAttrib1dv::load_with(|s| loadfn(s));
-    VertexAttrib1f::load_with(|s| loadfn(s));
-    VertexAttrib1fv::load_with(|s| loadfn(s));
-    VertexAttrib1s::load_with(|s| loadfn(s));
-    VertexAttrib1sv::load_with(|s| loadfn(s));
-    VertexAttrib2d::load_with(|s| loadfn(s));
-    VertexAttrib2dv::load_with(|s| loadfn(s));
-    VertexAttrib2f::load_with(|s| loadfn(s));
-    VertexAttrib2fv::load_with(|s| loadfn(s));
-    VertexAttrib2s::load_with(|s| loadfn(s));
-    VertexAttrib2sv::load_with(|s| loadfn(s));
-    VertexAttrib3d::load_with(|s| loadfn(s));
-    VertexAttrib3dv::load_with(|s| loadfn(s));
-    VertexAttrib3f::load_with(|s| loadfn(s));
-    VertexAttrib3fv::load_with(|s| loadfn(s));
-    VertexAttrib3s::load_with(|s| loadfn(s));
-    VertexAttrib3sv::load_with(|s| loadfn(s));
-    VertexAttrib4Nbv::load_with(|s| loadfn(s));
-    VertexAttrib4Niv::load_with(|s| loadfn(s));
-    VertexAttrib4Nsv::load_with(|s| loadfn(s));
-    VertexAttrib4Nub::load_with(|s| loadfn(s));
-    VertexAttrib4Nubv::load_with(|s| loadfn(s));
-    VertexAttrib4Nuiv::load_with(|s| loadfn(s));
-    VertexAttrib4Nusv::load_with(|s| loadfn(s));
-    VertexAttrib4bv::load_with(|s| loadfn(s));
-    VertexAttrib4d::load_with(|s| loadfn(s));
-    VertexAttrib4dv::load_with(|s| loadfn(s));
-    VertexAttrib4f::load_with(|s| loadfn(s));
-    VertexAttrib4fv::load_with(|s| loadfn(s));
-    VertexAttrib4iv::load_with(|s| loadfn(s));
-    VertexAttrib4s::load_with(|s| loadfn(s));
-    VertexAttrib4sv::load_with(|s| loadfn(s));
-    VertexAttrib4ubv::load_with(|s| loadfn(s));
-    VertexAttrib4uiv::load_with(|s| loadfn(s));
-    VertexAttrib4usv::load_with(|s| loadfn(s));
-    VertexAttribBinding::load_with(|s| loadfn(s));
-    VertexAttribDivisor::load_with(|s| loadfn(s));
-    VertexAttribFormat::load_with(|s| loadfn(s));
-    VertexAttribI1i::load_with(|s| loadfn(s));
-    VertexAttribI1iv::load_with(|s| loadfn(s));
-    VertexAttribI1ui::load_with(|s| loadfn(s));
-    VertexAttribI1uiv::load_with(|s| loadfn(s));
-    VertexAttribI2i::load_with(|s| loadfn(s));
-    VertexAttribI2iv::load_with(|s| loadfn(s));
-    VertexAttribI2ui::load_with(|s| loadfn(s));
-    VertexAttribI2uiv::load_with(|s| loadfn(s));
-    VertexAttribI3i::load_with(|s| loadfn(s));
-    VertexAttribI3iv::load_with(|s| loadfn(s));
-    VertexAttribI3ui::load_with(|s| loadfn(s));
-    VertexAttribI3uiv::load_with(|s| loadfn(s));
-    VertexAttribI4bv::load_with(|s| loadfn(s));
-    VertexAttribI4i::load_with(|s| loadfn(s));
-    VertexAttribI4iv::load_with(|s| loadfn(s));
-    VertexAttribI4sv::load_with(|s| loadfn(s));
-    VertexAttribI4ubv::load_with(|s| loadfn(s));
-    VertexAttribI4ui::load_with(|s| loadfn(s));
-    VertexAttribI4uiv::load_with(|s| loadfn(s));
-    VertexAttribI4usv::load_with(|s| loadfn(s));
-    VertexAttribIFormat::load_with(|s| loadfn(s));
-    VertexAttribIPointer::load_with(|s| loadfn(s));
-    VertexAttribL1d::load_with(|s| loadfn(s));
-    VertexAttribL1dv::load_with(|s| loadfn(s));
-    VertexAttribL2d::load_with(|s| loadfn(s));
-    VertexAttribL2dv::load_with(|s| loadfn(s));
-    VertexAttribL3d::load_with(|s| loadfn(s));
-    VertexAttribL3dv::load_with(|s| loadfn(s));
-    VertexAttribL4d::load_with(|s| loadfn(s));
-    VertexAttribL4dv::load_with(|s| loadfn(s));
-    VertexAttribLFormat::load_with(|s| loadfn(s));
-    VertexAttribLPointer::load_with(|s| loadfn(s));
-    VertexAttribP1ui::load_with(|s| loadfn(s));
-    VertexAttribP1uiv::load_with(|s| loadfn(s));
-    VertexAttribP2ui::load_with(|s| loadfn(s));
-    VertexAttribP2uiv::load_with(|s| loadfn(s));
-    VertexAttribP3ui::load_with(|s| loadfn(s));
-    VertexAttribP3uiv::load_with(|s| loadfn(s));
-    VertexAttribP4ui::load_with(|s| loadfn(s));
-    VertexAttribP4uiv::load_with(|s| loadfn(s));
-    VertexAttribPointer::load_with(|s| loadfn(s));
-    VertexBindingDivisor::load_with(|s| loadfn(s));
-    VertexP2ui::load_with(|s| loadfn(s));
-    VertexP2uiv::load_with(|s| loadfn(s));
-    VertexP3ui::load_with(|s| loadfn(s));
-    VertexP3uiv::load_with(|s| loadfn(s));
-    VertexP4ui::load_with(|s| loadfn(s));
-    VertexP4uiv::load_with(|s| loadfn(s));
-    Viewport::load_with(|s| loadfn(s));
-    ViewportArrayv::load_with(|s| loadfn(s));
-    ViewportIndexedf::load_with(|s| loadfn(s));
-    ViewportIndexedfv::load_with(|s| loadfn(s));
-    WaitSync::load_with(|s| loadfn(s));
+pub fn load_with(loadfn: &fn(symbol: &str) -> Option<extern "C" fn()>) -> LoadReport {
+    let mut report = LoadReport { requested: "gl 4.3 core", loaded: ~[], missing: ~[] };
+    report.record("glActiveShaderProgram", ActiveShaderProgram::load_with(|s| loadfn(s)));
+    report.record("glActiveTexture", ActiveTexture::load_with(|s| loadfn(s)));
+    report.record("glAttachShader", AttachShader::load_with(|s| loadfn(s)));
+    report.record("glBeginConditionalRender", BeginConditionalRender::load_with(|s| loadfn(s)));
+    report.record("glBeginQuery", BeginQuery::load_with(|s| loadfn(s)));
+    report.record("glBeginQueryIndexed", BeginQueryIndexed::load_with(|s| loadfn(s)));
+    report.record("glBeginTransformFeedback", BeginTransformFeedback::load_with(|s| loadfn(s)));
+    report.record("glBindAttribLocation", BindAttribLocation::load_with(|s| loadfn(s)));
+    report.record("glBindBuffer", BindBuffer::load_with(|s| loadfn(s)));
+    report.record("glBindBufferBase", BindBufferBase::load_with(|s| loadfn(s)));
+    report.record("glBindBufferRange", BindBufferRange::load_with(|s| loadfn(s)));
+    report.record("glBindFragDataLocation", BindFragDataLocation::load_with(|s| loadfn(s)));
+    report.record("glBindFragDataLocationIndexed", BindFragDataLocationIndexed::load_with(|s| loadfn(s)));
+    report.record("glBindFramebuffer", BindFramebuffer::load_with(|s| loadfn(s)));
+    report.record("glBindImageTexture", BindImageTexture::load_with(|s| loadfn(s)));
+    report.record("glBindProgramPipeline", BindProgramPipeline::load_with(|s| loadfn(s)));
+    report.record("glBindRenderbuffer", BindRenderbuffer::load_with(|s| loadfn(s)));
+    report.record("glBindSampler", BindSampler::load_with(|s| loadfn(s)));
+    report.record("glBindTexture", BindTexture::load_with(|s| loadfn(s)));
+    report.record("glBindTransformFeedback", BindTransformFeedback::load_with(|s| loadfn(s)));
+    report.record("glBindVertexArray", BindVertexArray::load_with(|s| loadfn(s)));
+    report.record("glBindVertexBuffer", BindVertexBuffer::load_with(|s| loadfn(s)));
+    report.record("glBlendColor", BlendColor::load_with(|s| loadfn(s)));
+    report.record("glBlendEquation", BlendEquation::load_with(|s| loadfn(s)));
+    report.record("glBlendEquationSeparate", BlendEquationSeparate::load_with(|s| loadfn(s)));
+    report.record("glBlendEquationSeparatei", BlendEquationSeparatei::load_with(|s| loadfn(s)));
+    report.record("glBlendEquationi", BlendEquationi::load_with(|s| loadfn(s)));
+    report.record("glBlendFunc", BlendFunc::load_with(|s| loadfn(s)));
+    report.record("glBlendFuncSeparate", BlendFuncSeparate::load_with(|s| loadfn(s)));
+    report.record("glBlendFuncSeparatei", BlendFuncSeparatei::load_with(|s| loadfn(s)));
+    report.record("glBlendFunci", BlendFunci::load_with(|s| loadfn(s)));
+    report.record("glBlitFramebuffer", BlitFramebuffer::load_with(|s| loadfn(s)));
+    report.record("glBufferData", BufferData::load_with(|s| loadfn(s)));
+    report.record("glBufferSubData", BufferSubData::load_with(|s| loadfn(s)));
+    report.record("glCheckFramebufferStatus", CheckFramebufferStatus::load_with(|s| loadfn(s)));
+    report.record("glClampColor", ClampColor::load_with(|s| loadfn(s)));
+    report.record("glClear", Clear::load_with(|s| loadfn(s)));
+    report.record("glClearBufferData", ClearBufferData::load_with(|s| loadfn(s)));
+    report.record("glClearBufferSubData", ClearBufferSubData::load_with(|s| loadfn(s)));
+    report.record("glClearBufferfi", ClearBufferfi::load_with(|s| loadfn(s)));
+    report.record("glClearBufferfv", ClearBufferfv::load_with(|s| loadfn(s)));
+    report.record("glClearBufferiv", ClearBufferiv::load_with(|s| loadfn(s)));
+    report.record("glClearBufferuiv", ClearBufferuiv::load_with(|s| loadfn(s)));
+    report.record("glClearColor", ClearColor::load_with(|s| loadfn(s)));
+    report.record("glClearDepth", ClearDepth::load_with(|s| loadfn(s)));
+    report.record("glClearDepthf", ClearDepthf::load_with(|s| loadfn(s)));
+    report.record("glClearStencil", ClearStencil::load_with(|s| loadfn(s)));
+    report.record("glClientWaitSync", ClientWaitSync::load_with(|s| loadfn(s)));
+    report.record("glColorMask", ColorMask::load_with(|s| loadfn(s)));
+    report.record("glColorMaski", ColorMaski::load_with(|s| loadfn(s)));
+    report.record("glColorP3ui", ColorP3ui::load_with(|s| loadfn(s)));
+    report.record("glColorP3uiv", ColorP3uiv::load_with(|s| loadfn(s)));
+    report.record("glColorP4ui", ColorP4ui::load_with(|s| loadfn(s)));
+    report.record("glColorP4uiv", ColorP4uiv::load_with(|s| loadfn(s)));
+    report.record("glCompileShader", CompileShader::load_with(|s| loadfn(s)));
+    report.record("glCompressedTexImage1D", CompressedTexImage1D::load_with(|s| loadfn(s)));
+    report.record("glCompressedTexImage2D", CompressedTexImage2D::load_with(|s| loadfn(s)));
+    report.record("glCompressedTexImage3D", CompressedTexImage3D::load_with(|s| loadfn(s)));
+    report.record("glCompressedTexSubImage1D", CompressedTexSubImage1D::load_with(|s| loadfn(s)));
+    report.record("glCompressedTexSubImage2D", CompressedTexSubImage2D::load_with(|s| loadfn(s)));
+    report.record("glCompressedTexSubImage3D", CompressedTexSubImage3D::load_with(|s| loadfn(s)));
+    report.record("glCopyBufferSubData", CopyBufferSubData::load_with(|s| loadfn(s)));
+    report.record("glCopyImageSubData", CopyImageSubData::load_with(|s| loadfn(s)));
+    report.record("glCopyTexImage1D", CopyTexImage1D::load_with(|s| loadfn(s)));
+    report.record("glCopyTexImage2D", CopyTexImage2D::load_with(|s| loadfn(s)));
+    report.record("glCopyTexSubImage1D", CopyTexSubImage1D::load_with(|s| loadfn(s)));
+    report.record("glCopyTexSubImage2D", CopyTexSubImage2D::load_with(|s| loadfn(s)));
+    report.record("glCopyTexSubImage3D", CopyTexSubImage3D::load_with(|s| loadfn(s)));
+    report.record("glCreateProgram", CreateProgram::load_with(|s| loadfn(s)));
+    report.record("glCreateShader", CreateShader::load_with(|s| loadfn(s)));
+    report.record("glCreateShaderProgramv", CreateShaderProgramv::load_with(|s| loadfn(s)));
+    report.record("glCullFace", CullFace::load_with(|s| loadfn(s)));
+    report.record("glDebugMessageCallback", DebugMessageCallback::load_with(|s| loadfn(s)));
+    report.record("glDebugMessageControl", DebugMessageControl::load_with(|s| loadfn(s)));
+    report.record("glDebugMessageInsert", DebugMessageInsert::load_with(|s| loadfn(s)));
+    report.record("glDeleteBuffers", DeleteBuffers::load_with(|s| loadfn(s)));
+    report.record("glDeleteFramebuffers", DeleteFramebuffers::load_with(|s| loadfn(s)));
+    report.record("glDeleteProgram", DeleteProgram::load_with(|s| loadfn(s)));
+    report.record("glDeleteProgramPipelines", DeleteProgramPipelines::load_with(|s| loadfn(s)));
+    report.record("glDeleteQueries", DeleteQueries::load_with(|s| loadfn(s)));
+    report.record("glDeleteRenderbuffers", DeleteRenderbuffers::load_with(|s| loadfn(s)));
+    report.record("glDeleteSamplers", DeleteSamplers::load_with(|s| loadfn(s)));
+    report.record("glDeleteShader", DeleteShader::load_with(|s| loadfn(s)));
+    report.record("glDeleteSync", DeleteSync::load_with(|s| loadfn(s)));
+    report.record("glDeleteTextures", DeleteTextures::load_with(|s| loadfn(s)));
+    report.record("glDeleteTransformFeedbacks", DeleteTransformFeedbacks::load_with(|s| loadfn(s)));
+    report.record("glDeleteVertexArrays", DeleteVertexArrays::load_with(|s| loadfn(s)));
+    report.record("glDepthFunc", DepthFunc::load_with(|s| loadfn(s)));
+    report.record("glDepthMask", DepthMask::load_with(|s| loadfn(s)));
+    report.record("glDepthRange", DepthRange::load_with(|s| loadfn(s)));
+    report.record("glDepthRangeArrayv", DepthRangeArrayv::load_with(|s| loadfn(s)));
+    report.record("glDepthRangeIndexed", DepthRangeIndexed::load_with(|s| loadfn(s)));
+    report.record("glDepthRangef", DepthRangef::load_with(|s| loadfn(s)));
+    report.record("glDetachShader", DetachShader::load_with(|s| loadfn(s)));
+    report.record("glDisable", Disable::load_with(|s| loadfn(s)));
+    report.record("glDisableVertexAttribArray", DisableVertexAttribArray::load_with(|s| loadfn(s)));
+    report.record("glDisablei", Disablei::load_with(|s| loadfn(s)));
+    report.record("glDispatchCompute", DispatchCompute::load_with(|s| loadfn(s)));
+    report.record("glDispatchComputeIndirect", DispatchComputeIndirect::load_with(|s| loadfn(s)));
+    report.record("glDrawArrays", DrawArrays::load_with(|s| loadfn(s)));
+    report.record("glDrawArraysIndirect", DrawArraysIndirect::load_with(|s| loadfn(s)));
+    report.record("glDrawArraysInstanced", DrawArraysInstanced::load_with(|s| loadfn(s)));
+    report.record("glDrawArraysInstancedBaseInstance", DrawArraysInstancedBaseInstance::load_with(|s| loadfn(s)));
+    report.record("glDrawBuffer", DrawBuffer::load_with(|s| loadfn(s)));
+    report.record("glDrawBuffers", DrawBuffers::load_with(|s| loadfn(s)));
+    report.record("glDrawElements", DrawElements::load_with(|s| loadfn(s)));
+    report.record("glDrawElementsBaseVertex", DrawElementsBaseVertex::load_with(|s| loadfn(s)));
+    report.record("glDrawElementsIndirect", DrawElementsIndirect::load_with(|s| loadfn(s)));
+    report.record("glDrawElementsInstanced", DrawElementsInstanced::load_with(|s| loadfn(s)));
+    report.record("glDrawElementsInstancedBaseInstance", DrawElementsInstancedBaseInstance::load_with(|s| loadfn(s)));
+    report.record("glDrawElementsInstancedBaseVertex", DrawElementsInstancedBaseVertex::load_with(|s| loadfn(s)));
+    report.record("glDrawElementsInstancedBaseVertexBaseInstance", DrawElementsInstancedBaseVertexBaseInstance::load_with(|s| loadfn(s)));
+    report.record("glDrawRangeElements", DrawRangeElements::load_with(|s| loadfn(s)));
+    report.record("glDrawRangeElementsBaseVertex", DrawRangeElementsBaseVertex::load_with(|s| loadfn(s)));
+    report.record("glDrawTransformFeedback", DrawTransformFeedback::load_with(|s| loadfn(s)));
+    report.record("glDrawTransformFeedbackInstanced", DrawTransformFeedbackInstanced::load_with(|s| loadfn(s)));
+    report.record("glDrawTransformFeedbackStream", DrawTransformFeedbackStream::load_with(|s| loadfn(s)));
+    report.record("glDrawTransformFeedbackStreamInstanced", DrawTransformFeedbackStreamInstanced::load_with(|s| loadfn(s)));
+    report.record("glEnable", Enable::load_with(|s| loadfn(s)));
+    report.record("glEnableVertexAttribArray", EnableVertexAttribArray::load_with(|s| loadfn(s)));
+    report.record("glEnablei", Enablei::load_with(|s| loadfn(s)));
+    report.record("glEndConditionalRender", EndConditionalRender::load_with(|s| loadfn(s)));
+    report.record("glEndQuery", EndQuery::load_with(|s| loadfn(s)));
+    report.record("glEndQueryIndexed", EndQueryIndexed::load_with(|s| loadfn(s)));
+    report.record("glEndTransformFeedback", EndTransformFeedback::load_with(|s| loadfn(s)));
+    report.record("glFenceSync", FenceSync::load_with(|s| loadfn(s)));
+    report.record("glFinish", Finish::load_with(|s| loadfn(s)));
+    report.record("glFlush", Flush::load_with(|s| loadfn(s)));
+    report.record("glFlushMappedBufferRange", FlushMappedBufferRange::load_with(|s| loadfn(s)));
+    report.record("glFramebufferParameteri", FramebufferParameteri::load_with(|s| loadfn(s)));
+    report.record("glFramebufferRenderbuffer", FramebufferRenderbuffer::load_with(|s| loadfn(s)));
+    report.record("glFramebufferTexture", FramebufferTexture::load_with(|s| loadfn(s)));
+    report.record("glFramebufferTexture1D", FramebufferTexture1D::load_with(|s| loadfn(s)));
+    report.record("glFramebufferTexture2D", FramebufferTexture2D::load_with(|s| loadfn(s)));
+    report.record("glFramebufferTexture3D", FramebufferTexture3D::load_with(|s| loadfn(s)));
+    report.record("glFramebufferTextureLayer", FramebufferTextureLayer::load_with(|s| loadfn(s)));
+    report.record("glFrontFace", FrontFace::load_with(|s| loadfn(s)));
+    report.record("glGenBuffers", GenBuffers::load_with(|s| loadfn(s)));
+    report.record("glGenFramebuffers", GenFramebuffers::load_with(|s| loadfn(s)));
+    report.record("glGenProgramPipelines", GenProgramPipelines::load_with(|s| loadfn(s)));
+    report.record("glGenQueries", GenQueries::load_with(|s| loadfn(s)));
+    report.record("glGenRenderbuffers", GenRenderbuffers::load_with(|s| loadfn(s)));
+    report.record("glGenSamplers", GenSamplers::load_with(|s| loadfn(s)));
+    report.record("glGenTextures", GenTextures::load_with(|s| loadfn(s)));
+    report.record("glGenTransformFeedbacks", GenTransformFeedbacks::load_with(|s| loadfn(s)));
+    report.record("glGenVertexArrays", GenVertexArrays::load_with(|s| loadfn(s)));
+    report.record("glGenerateMipmap", GenerateMipmap::load_with(|s| loadfn(s)));
+    report.record("glGetActiveAtomicCounterBufferiv", GetActiveAtomicCounterBufferiv::load_with(|s| loadfn(s)));
+    report.record("glGetActiveAttrib", GetActiveAttrib::load_with(|s| loadfn(s)));
+    report.record("glGetActiveSubroutineName", GetActiveSubroutineName::load_with(|s| loadfn(s)));
+    report.record("glGetActiveSubroutineUniformName", GetActiveSubroutineUniformName::load_with(|s| loadfn(s)));
+    report.record("glGetActiveSubroutineUniformiv", GetActiveSubroutineUniformiv::load_with(|s| loadfn(s)));
+    report.record("glGetActiveUniform", GetActiveUniform::load_with(|s| loadfn(s)));
+    report.record("glGetActiveUniformBlockName", GetActiveUniformBlockName::load_with(|s| loadfn(s)));
+    report.record("glGetActiveUniformBlockiv", GetActiveUniformBlockiv::load_with(|s| loadfn(s)));
+    report.record("glGetActiveUniformName", GetActiveUniformName::load_with(|s| loadfn(s)));
+    report.record("glGetActiveUniformsiv", GetActiveUniformsiv::load_with(|s| loadfn(s)));
+    report.record("glGetAttachedShaders", GetAttachedShaders::load_with(|s| loadfn(s)));
+    report.record("glGetAttribLocation", GetAttribLocation::load_with(|s| loadfn(s)));
+    report.record("glGetBooleani_v", GetBooleani_v::load_with(|s| loadfn(s)));
+    report.record("glGetBooleanv", GetBooleanv::load_with(|s| loadfn(s)));
+    report.record("glGetBufferParameteri64v", GetBufferParameteri64v::load_with(|s| loadfn(s)));
+    report.record("glGetBufferParameteriv", GetBufferParameteriv::load_with(|s| loadfn(s)));
+    report.record("glGetBufferPointerv", GetBufferPointerv::load_with(|s| loadfn(s)));
+    report.record("glGetBufferSubData", GetBufferSubData::load_with(|s| loadfn(s)));
+    report.record("glGetCompressedTexImage", GetCompressedTexImage::load_with(|s| loadfn(s)));
+    report.record("glGetDebugMessageLog", GetDebugMessageLog::load_with(|s| loadfn(s)));
+    report.record("glGetDoublei_v", GetDoublei_v::load_with(|s| loadfn(s)));
+    report.record("glGetDoublev", GetDoublev::load_with(|s| loadfn(s)));
+    report.record("glGetError", GetError::load_with(|s| loadfn(s)));
+    report.record("glGetFloati_v", GetFloati_v::load_with(|s| loadfn(s)));
+    report.record("glGetFloatv", GetFloatv::load_with(|s| loadfn(s)));
+    report.record("glGetFragDataIndex", GetFragDataIndex::load_with(|s| loadfn(s)));
+    report.record("glGetFragDataLocation", GetFragDataLocation::load_with(|s| loadfn(s)));
+    report.record("glGetFramebufferAttachmentParameteriv", GetFramebufferAttachmentParameteriv::load_with(|s| loadfn(s)));
+    report.record("glGetFramebufferParameteriv", GetFramebufferParameteriv::load_with(|s| loadfn(s)));
+    report.record("glGetInteger64i_v", GetInteger64i_v::load_with(|s| loadfn(s)));
+    report.record("glGetInteger64v", GetInteger64v::load_with(|s| loadfn(s)));
+    report.record("glGetIntegeri_v", GetIntegeri_v::load_with(|s| loadfn(s)));
+    report.record("glGetIntegerv", GetIntegerv::load_with(|s| loadfn(s)));
+    report.record("glGetInternalformati64v", GetInternalformati64v::load_with(|s| loadfn(s)));
+    report.record("glGetInternalformativ", GetInternalformativ::load_with(|s| loadfn(s)));
+    report.record("glGetMultisamplefv", GetMultisamplefv::load_with(|s| loadfn(s)));
+    report.record("glGetObjectLabel", GetObjectLabel::load_with(|s| loadfn(s)));
+    report.record("glGetObjectPtrLabel", GetObjectPtrLabel::load_with(|s| loadfn(s)));
+    report.record("glGetProgramBinary", GetProgramBinary::load_with(|s| loadfn(s)));
+    report.record("glGetProgramInfoLog", GetProgramInfoLog::load_with(|s| loadfn(s)));
+    report.record("glGetProgramInterfaceiv", GetProgramInterfaceiv::load_with(|s| loadfn(s)));
+    report.record("glGetProgramPipelineInfoLog", GetProgramPipelineInfoLog::load_with(|s| loadfn(s)));
+    report.record("glGetProgramPipelineiv", GetProgramPipelineiv::load_with(|s| loadfn(s)));
+    report.record("glGetProgramResourceIndex", GetProgramResourceIndex::load_with(|s| loadfn(s)));
+    report.record("glGetProgramResourceLocation", GetProgramResourceLocation::load_with(|s| loadfn(s)));
+    report.record("glGetProgramResourceLocationIndex", GetProgramResourceLocationIndex::load_with(|s| loadfn(s)));
+    report.record("glGetProgramResourceName", GetProgramResourceName::load_with(|s| loadfn(s)));
+    report.record("glGetProgramResourceiv", GetProgramResourceiv::load_with(|s| loadfn(s)));
+    report.record("glGetProgramStageiv", GetProgramStageiv::load_with(|s| loadfn(s)));
+    report.record("glGetProgramiv", GetProgramiv::load_with(|s| loadfn(s)));
+    report.record("glGetQueryIndexediv", GetQueryIndexediv::load_with(|s| loadfn(s)));
+    report.record("glGetQueryObjecti64v", GetQueryObjecti64v::load_with(|s| loadfn(s)));
+    report.record("glGetQueryObjectiv", GetQueryObjectiv::load_with(|s| loadfn(s)));
+    report.record("glGetQueryObjectui64v", GetQueryObjectui64v::load_with(|s| loadfn(s)));
+    report.record("glGetQueryObjectuiv", GetQueryObjectuiv::load_with(|s| loadfn(s)));
+    report.record("glGetQueryiv", GetQueryiv::load_with(|s| loadfn(s)));
+    report.record("glGetRenderbufferParameteriv", GetRenderbufferParameteriv::load_with(|s| loadfn(s)));
+    report.record("glGetSamplerParameterIiv", GetSamplerParameterIiv::load_with(|s| loadfn(s)));
+    report.record("glGetSamplerParameterIuiv", GetSamplerParameterIuiv::load_with(|s| loadfn(s)));
+    report.record("glGetSamplerParameterfv", GetSamplerParameterfv::load_with(|s| loadfn(s)));
+    report.record("glGetSamplerParameteriv", GetSamplerParameteriv::load_with(|s| loadfn(s)));
+    report.record("glGetShaderInfoLog", GetShaderInfoLog::load_with(|s| loadfn(s)));
+    report.record("glGetShaderPrecisionFormat", GetShaderPrecisionFormat::load_with(|s| loadfn(s)));
+    report.record("glGetShaderSource", GetShaderSource::load_with(|s| loadfn(s)));
+    report.record("glGetShaderiv", GetShaderiv::load_with(|s| loadfn(s)));
+    report.record("glGetString", GetString::load_with(|s| loadfn(s)));
+    report.record("glGetStringi", GetStringi::load_with(|s| loadfn(s)));
+    report.record("glGetSubroutineIndex", GetSubroutineIndex::load_with(|s| loadfn(s)));
+    report.record("glGetSubroutineUniformLocation", GetSubroutineUniformLocation::load_with(|s| loadfn(s)));
+    report.record("glGetSynciv", GetSynciv::load_with(|s| loadfn(s)));
+    report.record("glGetTexImage", GetTexImage::load_with(|s| loadfn(s)));
+    report.record("glGetTexLevelParameterfv", GetTexLevelParameterfv::load_with(|s| loadfn(s)));
+    report.record("glGetTexLevelParameteriv", GetTexLevelParameteriv::load_with(|s| loadfn(s)));
+    report.record("glGetTexParameterIiv", GetTexParameterIiv::load_with(|s| loadfn(s)));
+    report.record("glGetTexParameterIuiv", GetTexParameterIuiv::load_with(|s| loadfn(s)));
+    report.record("glGetTexParameterfv", GetTexParameterfv::load_with(|s| loadfn(s)));
+    report.record("glGetTexParameteriv", GetTexParameteriv::load_with(|s| loadfn(s)));
+    report.record("glGetTransformFeedbackVarying", GetTransformFeedbackVarying::load_with(|s| loadfn(s)));
+    report.record("glGetUniformBlockIndex", GetUniformBlockIndex::load_with(|s| loadfn(s)));
+    report.record("glGetUniformIndices", GetUniformIndices::load_with(|s| loadfn(s)));
+    report.record("glGetUniformLocation", GetUniformLocation::load_with(|s| loadfn(s)));
+    report.record("glGetUniformSubroutineuiv", GetUniformSubroutineuiv::load_with(|s| loadfn(s)));
+    report.record("glGetUniformdv", GetUniformdv::load_with(|s| loadfn(s)));
+    report.record("glGetUniformfv", GetUniformfv::load_with(|s| loadfn(s)));
+    report.record("glGetUniformiv", GetUniformiv::load_with(|s| loadfn(s)));
+    report.record("glGetUniformuiv", GetUniformuiv::load_with(|s| loadfn(s)));
+    report.record("glGetVertexAttribIiv", GetVertexAttribIiv::load_with(|s| loadfn(s)));
+    report.record("glGetVertexAttribIuiv", GetVertexAttribIuiv::load_with(|s| loadfn(s)));
+    report.record("glGetVertexAttribLdv", GetVertexAttribLdv::load_with(|s| loadfn(s)));
+    report.record("glGetVertexAttribPointerv", GetVertexAttribPointerv::load_with(|s| loadfn(s)));
+    report.record("glGetVertexAttribdv", GetVertexAttribdv::load_with(|s| loadfn(s)));
+    report.record("glGetVertexAttribfv", GetVertexAttribfv::load_with(|s| loadfn(s)));
+    report.record("glGetVertexAttribiv", GetVertexAttribiv::load_with(|s| loadfn(s)));
+    report.record("glHint", Hint::load_with(|s| loadfn(s)));
+    report.record("glInvalidateBufferData", InvalidateBufferData::load_with(|s| loadfn(s)));
+    report.record("glInvalidateBufferSubData", InvalidateBufferSubData::load_with(|s| loadfn(s)));
+    report.record("glInvalidateFramebuffer", InvalidateFramebuffer::load_with(|s| loadfn(s)));
+    report.record("glInvalidateSubFramebuffer", InvalidateSubFramebuffer::load_with(|s| loadfn(s)));
+    report.record("glInvalidateTexImage", InvalidateTexImage::load_with(|s| loadfn(s)));
+    report.record("glInvalidateTexSubImage", InvalidateTexSubImage::load_with(|s| loadfn(s)));
+    report.record("glIsBuffer", IsBuffer::load_with(|s| loadfn(s)));
+    report.record("glIsEnabled", IsEnabled::load_with(|s| loadfn(s)));
+    report.record("glIsEnabledi", IsEnabledi::load_with(|s| loadfn(s)));
+    report.record("glIsFramebuffer", IsFramebuffer::load_with(|s| loadfn(s)));
+    report.record("glIsProgram", IsProgram::load_with(|s| loadfn(s)));
+    report.record("glIsProgramPipeline", IsProgramPipeline::load_with(|s| loadfn(s)));
+    report.record("glIsQuery", IsQuery::load_with(|s| loadfn(s)));
+    report.record("glIsRenderbuffer", IsRenderbuffer::load_with(|s| loadfn(s)));
+    report.record("glIsSampler", IsSampler::load_with(|s| loadfn(s)));
+    report.record("glIsShader", IsShader::load_with(|s| loadfn(s)));
+    report.record("glIsSync", IsSync::load_with(|s| loadfn(s)));
+    report.record("glIsTexture", IsTexture::load_with(|s| loadfn(s)));
+    report.record("glIsTransformFeedback", IsTransformFeedback::load_with(|s| loadfn(s)));
+    report.record("glIsVertexArray", IsVertexArray::load_with(|s| loadfn(s)));
+    report.record("glLineWidth", LineWidth::load_with(|s| loadfn(s)));
+    report.record("glLinkProgram", LinkProgram::load_with(|s| loadfn(s)));
+    report.record("glLogicOp", LogicOp::load_with(|s| loadfn(s)));
+    report.record("glMapBuffer", MapBuffer::load_with(|s| loadfn(s)));
+    report.record("glMapBufferRange", MapBufferRange::load_with(|s| loadfn(s)));
+    report.record("glMemoryBarrier", MemoryBarrier::load_with(|s| loadfn(s)));
+    report.record("glMinSampleShading", MinSampleShading::load_with(|s| loadfn(s)));
+    report.record("glMultiDrawArrays", MultiDrawArrays::load_with(|s| loadfn(s)));
+    report.record("glMultiDrawArraysIndirect", MultiDrawArraysIndirect::load_with(|s| loadfn(s)));
+    report.record("glMultiDrawElements", MultiDrawElements::load_with(|s| loadfn(s)));
+    report.record("glMultiDrawElementsBaseVertex", MultiDrawElementsBaseVertex::load_with(|s| loadfn(s)));
+    report.record("glMultiDrawElementsIndirect", MultiDrawElementsIndirect::load_with(|s| loadfn(s)));
+    report.record("glMultiTexCoordP1ui", MultiTexCoordP1ui::load_with(|s| loadfn(s)));
+    report.record("glMultiTexCoordP1uiv", MultiTexCoordP1uiv::load_with(|s| loadfn(s)));
+    report.record("glMultiTexCoordP2ui", MultiTexCoordP2ui::load_with(|s| loadfn(s)));
+    report.record("glMultiTexCoordP2uiv", MultiTexCoordP2uiv::load_with(|s| loadfn(s)));
+    report.record("glMultiTexCoordP3ui", MultiTexCoordP3ui::load_with(|s| loadfn(s)));
+    report.record("glMultiTexCoordP3uiv", MultiTexCoordP3uiv::load_with(|s| loadfn(s)));
+    report.record("glMultiTexCoordP4ui", MultiTexCoordP4ui::load_with(|s| loadfn(s)));
+    report.record("glMultiTexCoordP4uiv", MultiTexCoordP4uiv::load_with(|s| loadfn(s)));
+    report.record("glNormalP3ui", NormalP3ui::load_with(|s| loadfn(s)));
+    report.record("glNormalP3uiv", NormalP3uiv::load_with(|s| loadfn(s)));
+    report.record("glObjectLabel", ObjectLabel::load_with(|s| loadfn(s)));
+    report.record("glObjectPtrLabel", ObjectPtrLabel::load_with(|s| loadfn(s)));
+    report.record("glPatchParameterfv", PatchParameterfv::load_with(|s| loadfn(s)));
+    report.record("glPatchParameteri", PatchParameteri::load_with(|s| loadfn(s)));
+    report.record("glPauseTransformFeedback", PauseTransformFeedback::load_with(|s| loadfn(s)));
+    report.record("glPixelStoref", PixelStoref::load_with(|s| loadfn(s)));
+    report.record("glPixelStorei", PixelStorei::load_with(|s| loadfn(s)));
+    report.record("glPointParameterf", PointParameterf::load_with(|s| loadfn(s)));
+    report.record("glPointParameterfv", PointParameterfv::load_with(|s| loadfn(s)));
+    report.record("glPointParameteri", PointParameteri::load_with(|s| loadfn(s)));
+    report.record("glPointParameteriv", PointParameteriv::load_with(|s| loadfn(s)));
+    report.record("glPointSize", PointSize::load_with(|s| loadfn(s)));
+    report.record("glPolygonMode", PolygonMode::load_with(|s| loadfn(s)));
+    report.record("glPolygonOffset", PolygonOffset::load_with(|s| loadfn(s)));
+    report.record("glPopDebugGroup", PopDebugGroup::load_with(|s| loadfn(s)));
+    report.record("glPrimitiveRestartIndex", PrimitiveRestartIndex::load_with(|s| loadfn(s)));
+    report.record("glProgramBinary", ProgramBinary::load_with(|s| loadfn(s)));
+    report.record("glProgramParameteri", ProgramParameteri::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform1d", ProgramUniform1d::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform1dv", ProgramUniform1dv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform1f", ProgramUniform1f::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform1fv", ProgramUniform1fv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform1i", ProgramUniform1i::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform1iv", ProgramUniform1iv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform1ui", ProgramUniform1ui::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform1uiv", ProgramUniform1uiv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform2d", ProgramUniform2d::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform2dv", ProgramUniform2dv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform2f", ProgramUniform2f::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform2fv", ProgramUniform2fv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform2i", ProgramUniform2i::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform2iv", ProgramUniform2iv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform2ui", ProgramUniform2ui::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform2uiv", ProgramUniform2uiv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform3d", ProgramUniform3d::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform3dv", ProgramUniform3dv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform3f", ProgramUniform3f::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform3fv", ProgramUniform3fv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform3i", ProgramUniform3i::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform3iv", ProgramUniform3iv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform3ui", ProgramUniform3ui::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform3uiv", ProgramUniform3uiv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform4d", ProgramUniform4d::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform4dv", ProgramUniform4dv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform4f", ProgramUniform4f::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform4fv", ProgramUniform4fv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform4i", ProgramUniform4i::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform4iv", ProgramUniform4iv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform4ui", ProgramUniform4ui::load_with(|s| loadfn(s)));
+    report.record("glProgramUniform4uiv", ProgramUniform4uiv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniformMatrix2dv", ProgramUniformMatrix2dv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniformMatrix2fv", ProgramUniformMatrix2fv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniformMatrix2x3dv", ProgramUniformMatrix2x3dv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniformMatrix2x3fv", ProgramUniformMatrix2x3fv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniformMatrix2x4dv", ProgramUniformMatrix2x4dv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniformMatrix2x4fv", ProgramUniformMatrix2x4fv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniformMatrix3dv", ProgramUniformMatrix3dv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniformMatrix3fv", ProgramUniformMatrix3fv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniformMatrix3x2dv", ProgramUniformMatrix3x2dv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniformMatrix3x2fv", ProgramUniformMatrix3x2fv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniformMatrix3x4dv", ProgramUniformMatrix3x4dv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniformMatrix3x4fv", ProgramUniformMatrix3x4fv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniformMatrix4dv", ProgramUniformMatrix4dv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniformMatrix4fv", ProgramUniformMatrix4fv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniformMatrix4x2dv", ProgramUniformMatrix4x2dv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniformMatrix4x2fv", ProgramUniformMatrix4x2fv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniformMatrix4x3dv", ProgramUniformMatrix4x3dv::load_with(|s| loadfn(s)));
+    report.record("glProgramUniformMatrix4x3fv", ProgramUniformMatrix4x3fv::load_with(|s| loadfn(s)));
+    report.record("glProvokingVertex", ProvokingVertex::load_with(|s| loadfn(s)));
+    report.record("glPushDebugGroup", PushDebugGroup::load_with(|s| loadfn(s)));
+    report.record("glQueryCounter", QueryCounter::load_with(|s| loadfn(s)));
+    report.record("glReadBuffer", ReadBuffer::load_with(|s| loadfn(s)));
+    report.record("glReadPixels", ReadPixels::load_with(|s| loadfn(s)));
+    report.record("glReleaseShaderCompiler", ReleaseShaderCompiler::load_with(|s| loadfn(s)));
+    report.record("glRenderbufferStorage", RenderbufferStorage::load_with(|s| loadfn(s)));
+    report.record("glRenderbufferStorageMultisample", RenderbufferStorageMultisample::load_with(|s| loadfn(s)));
+    report.record("glResumeTransformFeedback", ResumeTransformFeedback::load_with(|s| loadfn(s)));
+    report.record("glSampleCoverage", SampleCoverage::load_with(|s| loadfn(s)));
+    report.record("glSampleMaski", SampleMaski::load_with(|s| loadfn(s)));
+    report.record("glSamplerParameterIiv", SamplerParameterIiv::load_with(|s| loadfn(s)));
+    report.record("glSamplerParameterIuiv", SamplerParameterIuiv::load_with(|s| loadfn(s)));
+    report.record("glSamplerParameterf", SamplerParameterf::load_with(|s| loadfn(s)));
+    report.record("glSamplerParameterfv", SamplerParameterfv::load_with(|s| loadfn(s)));
+    report.record("glSamplerParameteri", SamplerParameteri::load_with(|s| loadfn(s)));
+    report.record("glSamplerParameteriv", SamplerParameteriv::load_with(|s| loadfn(s)));
+    report.record("glScissor", Scissor::load_with(|s| loadfn(s)));
+    report.record("glScissorArrayv", ScissorArrayv::load_with(|s| loadfn(s)));
+    report.record("glScissorIndexed", ScissorIndexed::load_with(|s| loadfn(s)));
+    report.record("glScissorIndexedv", ScissorIndexedv::load_with(|s| loadfn(s)));
+    report.record("glSecondaryColorP3ui", SecondaryColorP3ui::load_with(|s| loadfn(s)));
+    report.record("glSecondaryColorP3uiv", SecondaryColorP3uiv::load_with(|s| loadfn(s)));
+    report.record("glShaderBinary", ShaderBinary::load_with(|s| loadfn(s)));
+    report.record("glShaderSource", ShaderSource::load_with(|s| loadfn(s)));
+    report.record("glShaderStorageBlockBinding", ShaderStorageBlockBinding::load_with(|s| loadfn(s)));
+    report.record("glStencilFunc", StencilFunc::load_with(|s| loadfn(s)));
+    report.record("glStencilFuncSeparate", StencilFuncSeparate::load_with(|s| loadfn(s)));
+    report.record("glStencilMask", StencilMask::load_with(|s| loadfn(s)));
+    report.record("glStencilMaskSeparate", StencilMaskSeparate::load_with(|s| loadfn(s)));
+    report.record("glStencilOp", StencilOp::load_with(|s| loadfn(s)));
+    report.record("glStencilOpSeparate", StencilOpSeparate::load_with(|s| loadfn(s)));
+    report.record("glTexBuffer", TexBuffer::load_with(|s| loadfn(s)));
+    report.record("glTexBufferRange", TexBufferRange::load_with(|s| loadfn(s)));
+    report.record("glTexCoordP1ui", TexCoordP1ui::load_with(|s| loadfn(s)));
+    report.record("glTexCoordP1uiv", TexCoordP1uiv::load_with(|s| loadfn(s)));
+    report.record("glTexCoordP2ui", TexCoordP2ui::load_with(|s| loadfn(s)));
+    report.record("glTexCoordP2uiv", TexCoordP2uiv::load_with(|s| loadfn(s)));
+    report.record("glTexCoordP3ui", TexCoordP3ui::load_with(|s| loadfn(s)));
+    report.record("glTexCoordP3uiv", TexCoordP3uiv::load_with(|s| loadfn(s)));
+    report.record("glTexCoordP4ui", TexCoordP4ui::load_with(|s| loadfn(s)));
+    report.record("glTexCoordP4uiv", TexCoordP4uiv::load_with(|s| loadfn(s)));
+    report.record("glTexImage1D", TexImage1D::load_with(|s| loadfn(s)));
+    report.record("glTexImage2D", TexImage2D::load_with(|s| loadfn(s)));
+    report.record("glTexImage2DMultisample", TexImage2DMultisample::load_with(|s| loadfn(s)));
+    report.record("glTexImage3D", TexImage3D::load_with(|s| loadfn(s)));
+    report.record("glTexImage3DMultisample", TexImage3DMultisample::load_with(|s| loadfn(s)));
+    report.record("glTexParameterIiv", TexParameterIiv::load_with(|s| loadfn(s)));
+    report.record("glTexParameterIuiv", TexParameterIuiv::load_with(|s| loadfn(s)));
+    report.record("glTexParameterf", TexParameterf::load_with(|s| loadfn(s)));
+    report.record("glTexParameterfv", TexParameterfv::load_with(|s| loadfn(s)));
+    report.record("glTexParameteri", TexParameteri::load_with(|s| loadfn(s)));
+    report.record("glTexParameteriv", TexParameteriv::load_with(|s| loadfn(s)));
+    report.record("glTexStorage1D", TexStorage1D::load_with(|s| loadfn(s)));
+    report.record("glTexStorage2D", TexStorage2D::load_with(|s| loadfn(s)));
+    report.record("glTexStorage2DMultisample", TexStorage2DMultisample::load_with(|s| loadfn(s)));
+    report.record("glTexStorage3D", TexStorage3D::load_with(|s| loadfn(s)));
+    report.record("glTexStorage3DMultisample", TexStorage3DMultisample::load_with(|s| loadfn(s)));
+    report.record("glTexSubImage1D", TexSubImage1D::load_with(|s| loadfn(s)));
+    report.record("glTexSubImage2D", TexSubImage2D::load_with(|s| loadfn(s)));
+    report.record("glTexSubImage3D", TexSubImage3D::load_with(|s| loadfn(s)));
+    report.record("glTextureView", TextureView::load_with(|s| loadfn(s)));
+    report.record("glTransformFeedbackVaryings", TransformFeedbackVaryings::load_with(|s| loadfn(s)));
+    report.record("glUniform1d", Uniform1d::load_with(|s| loadfn(s)));
+    report.record("glUniform1dv", Uniform1dv::load_with(|s| loadfn(s)));
+    report.record("glUniform1f", Uniform1f::load_with(|s| loadfn(s)));
+    report.record("glUniform1fv", Uniform1fv::load_with(|s| loadfn(s)));
+    report.record("glUniform1i", Uniform1i::load_with(|s| loadfn(s)));
+    report.record("glUniform1iv", Uniform1iv::load_with(|s| loadfn(s)));
+    report.record("glUniform1ui", Uniform1ui::load_with(|s| loadfn(s)));
+    report.record("glUniform1uiv", Uniform1uiv::load_with(|s| loadfn(s)));
+    report.record("glUniform2d", Uniform2d::load_with(|s| loadfn(s)));
+    report.record("glUniform2dv", Uniform2dv::load_with(|s| loadfn(s)));
+    report.record("glUniform2f", Uniform2f::load_with(|s| loadfn(s)));
+    report.record("glUniform2fv", Uniform2fv::load_with(|s| loadfn(s)));
+    report.record("glUniform2i", Uniform2i::load_with(|s| loadfn(s)));
+    report.record("glUniform2iv", Uniform2iv::load_with(|s| loadfn(s)));
+    report.record("glUniform2ui", Uniform2ui::load_with(|s| loadfn(s)));
+    report.record("glUniform2uiv", Uniform2uiv::load_with(|s| loadfn(s)));
+    report.record("glUniform3d", Uniform3d::load_with(|s| loadfn(s)));
+    report.record("glUniform3dv", Uniform3dv::load_with(|s| loadfn(s)));
+    report.record("glUniform3f", Uniform3f::load_with(|s| loadfn(s)));
+    report.record("glUniform3fv", Uniform3fv::load_with(|s| loadfn(s)));
+    report.record("glUniform3i", Uniform3i::load_with(|s| loadfn(s)));
+    report.record("glUniform3iv", Uniform3iv::load_with(|s| loadfn(s)));
+    report.record("glUniform3ui", Uniform3ui::load_with(|s| loadfn(s)));
+    report.record("glUniform3uiv", Uniform3uiv::load_with(|s| loadfn(s)));
+    report.record("glUniform4d", Uniform4d::load_with(|s| loadfn(s)));
+    report.record("glUniform4dv", Uniform4dv::load_with(|s| loadfn(s)));
+    report.record("glUniform4f", Uniform4f::load_with(|s| loadfn(s)));
+    report.record("glUniform4fv", Uniform4fv::load_with(|s| loadfn(s)));
+    report.record("glUniform4i", Uniform4i::load_with(|s| loadfn(s)));
+    report.record("glUniform4iv", Uniform4iv::load_with(|s| loadfn(s)));
+    report.record("glUniform4ui", Uniform4ui::load_with(|s| loadfn(s)));
+    report.record("glUniform4uiv", Uniform4uiv::load_with(|s| loadfn(s)));
+    report.record("glUniformBlockBinding", UniformBlockBinding::load_with(|s| loadfn(s)));
+    report.record("glUniformMatrix2dv", UniformMatrix2dv::load_with(|s| loadfn(s)));
+    report.record("glUniformMatrix2fv", UniformMatrix2fv::load_with(|s| loadfn(s)));
+    report.record("glUniformMatrix2x3dv", UniformMatrix2x3dv::load_with(|s| loadfn(s)));
+    report.record("glUniformMatrix2x3fv", UniformMatrix2x3fv::load_with(|s| loadfn(s)));
+    report.record("glUniformMatrix2x4dv", UniformMatrix2x4dv::load_with(|s| loadfn(s)));
+    report.record("glUniformMatrix2x4fv", UniformMatrix2x4fv::load_with(|s| loadfn(s)));
+    report.record("glUniformMatrix3dv", UniformMatrix3dv::load_with(|s| loadfn(s)));
+    report.record("glUniformMatrix3fv", UniformMatrix3fv::load_with(|s| loadfn(s)));
+    report.record("glUniformMatrix3x2dv", UniformMatrix3x2dv::load_with(|s| loadfn(s)));
+    report.record("glUniformMatrix3x2fv", UniformMatrix3x2fv::load_with(|s| loadfn(s)));
+    report.record("glUniformMatrix3x4dv", UniformMatrix3x4dv::load_with(|s| loadfn(s)));
+    report.record("glUniformMatrix3x4fv", UniformMatrix3x4fv::load_with(|s| loadfn(s)));
+    report.record("glUniformMatrix4dv", UniformMatrix4dv::load_with(|s| loadfn(s)));
+    report.record("glUniformMatrix4fv", UniformMatrix4fv::load_with(|s| loadfn(s)));
+    report.record("glUniformMatrix4x2dv", UniformMatrix4x2dv::load_with(|s| loadfn(s)));
+    report.record("glUniformMatrix4x2fv", UniformMatrix4x2fv::load_with(|s| loadfn(s)));
+    report.record("glUniformMatrix4x3dv", UniformMatrix4x3dv::load_with(|s| loadfn(s)));
+    report.record("glUniformMatrix4x3fv", UniformMatrix4x3fv::load_with(|s| loadfn(s)));
+    report.record("glUniformSubroutinesuiv", UniformSubroutinesuiv::load_with(|s| loadfn(s)));
+    report.record("glUnmapBuffer", UnmapBuffer::load_with(|s| loadfn(s)));
+    report.record("glUseProgram", UseProgram::load_with(|s| loadfn(s)));
+    report.record("glUseProgramStages", UseProgramStages::load_with(|s| loadfn(s)));
+    report.record("glValidateProgram", ValidateProgram::load_with(|s| loadfn(s)));
+    report.record("glValidateProgramPipeline", ValidateProgramPipeline::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib1d", VertexAttrib1d::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib1dv", VertexAttrib1dv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib1f", VertexAttrib1f::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib1fv", VertexAttrib1fv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib1s", VertexAttrib1s::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib1sv", VertexAttrib1sv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib2d", VertexAttrib2d::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib2dv", VertexAttrib2dv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib2f", VertexAttrib2f::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib2fv", VertexAttrib2fv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib2s", VertexAttrib2s::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib2sv", VertexAttrib2sv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib3d", VertexAttrib3d::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib3dv", VertexAttrib3dv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib3f", VertexAttrib3f::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib3fv", VertexAttrib3fv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib3s", VertexAttrib3s::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib3sv", VertexAttrib3sv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib4Nbv", VertexAttrib4Nbv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib4Niv", VertexAttrib4Niv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib4Nsv", VertexAttrib4Nsv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib4Nub", VertexAttrib4Nub::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib4Nubv", VertexAttrib4Nubv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib4Nuiv", VertexAttrib4Nuiv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib4Nusv", VertexAttrib4Nusv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib4bv", VertexAttrib4bv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib4d", VertexAttrib4d::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib4dv", VertexAttrib4dv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib4f", VertexAttrib4f::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib4fv", VertexAttrib4fv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib4iv", VertexAttrib4iv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib4s", VertexAttrib4s::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib4sv", VertexAttrib4sv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib4ubv", VertexAttrib4ubv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib4uiv", VertexAttrib4uiv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttrib4usv", VertexAttrib4usv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribBinding", VertexAttribBinding::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribDivisor", VertexAttribDivisor::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribFormat", VertexAttribFormat::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribI1i", VertexAttribI1i::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribI1iv", VertexAttribI1iv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribI1ui", VertexAttribI1ui::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribI1uiv", VertexAttribI1uiv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribI2i", VertexAttribI2i::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribI2iv", VertexAttribI2iv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribI2ui", VertexAttribI2ui::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribI2uiv", VertexAttribI2uiv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribI3i", VertexAttribI3i::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribI3iv", VertexAttribI3iv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribI3ui", VertexAttribI3ui::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribI3uiv", VertexAttribI3uiv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribI4bv", VertexAttribI4bv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribI4i", VertexAttribI4i::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribI4iv", VertexAttribI4iv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribI4sv", VertexAttribI4sv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribI4ubv", VertexAttribI4ubv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribI4ui", VertexAttribI4ui::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribI4uiv", VertexAttribI4uiv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribI4usv", VertexAttribI4usv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribIFormat", VertexAttribIFormat::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribIPointer", VertexAttribIPointer::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribL1d", VertexAttribL1d::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribL1dv", VertexAttribL1dv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribL2d", VertexAttribL2d::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribL2dv", VertexAttribL2dv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribL3d", VertexAttribL3d::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribL3dv", VertexAttribL3dv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribL4d", VertexAttribL4d::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribL4dv", VertexAttribL4dv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribLFormat", VertexAttribLFormat::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribLPointer", VertexAttribLPointer::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribP1ui", VertexAttribP1ui::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribP1uiv", VertexAttribP1uiv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribP2ui", VertexAttribP2ui::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribP2uiv", VertexAttribP2uiv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribP3ui", VertexAttribP3ui::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribP3uiv", VertexAttribP3uiv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribP4ui", VertexAttribP4ui::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribP4uiv", VertexAttribP4uiv::load_with(|s| loadfn(s)));
+    report.record("glVertexAttribPointer", VertexAttribPointer::load_with(|s| loadfn(s)));
+    report.record("glVertexBindingDivisor", VertexBindingDivisor::load_with(|s| loadfn(s)));
+    report.record("glVertexP2ui", VertexP2ui::load_with(|s| loadfn(s)));
+    report.record("glVertexP2uiv", VertexP2uiv::load_with(|s| loadfn(s)));
+    report.record("glVertexP3ui", VertexP3ui::load_with(|s| loadfn(s)));
+    report.record("glVertexP3uiv", VertexP3uiv::load_with(|s| loadfn(s)));
+    report.record("glVertexP4ui", VertexP4ui::load_with(|s| loadfn(s)));
+    report.record("glVertexP4uiv", VertexP4uiv::load_with(|s| loadfn(s)));
+    report.record("glViewport", Viewport::load_with(|s| loadfn(s)));
+    report.record("glViewportArrayv", ViewportArrayv::load_with(|s| loadfn(s)));
+    report.record("glViewportIndexedf", ViewportIndexedf::load_with(|s| loadfn(s)));
+    report.record("glViewportIndexedfv", ViewportIndexedfv::load_with(|s| loadfn(s)));
+    report.record("glWaitSync", WaitSync::load_with(|s| loadfn(s)));
+    report
+}
+
+/// Restores every function pointer to its unloaded state, so that calling a
+/// function fails instead of using a stale driver pointer. This should be
+/// called when the context that the functions were loaded from is destroyed.
+pub fn unload() {
+    ActiveShaderProgram::unload();
+    ActiveTexture::unload();
+    AttachShader::unload();
+    BeginConditionalRender::unload();
+    BeginQuery::unload();
+    BeginQueryIndexed::unload();
+    BeginTransformFeedback::unload();
+    BindAttribLocation::unload();
+    BindBuffer::unload();
+    BindBufferBase::unload();
+    BindBufferRange::unload();
+    BindFragDataLocation::unload();
+    BindFragDataLocationIndexed::unload();
+    BindFramebuffer::unload();
+    BindImageTexture::unload();
+    BindProgramPipeline::unload();
+    BindRenderbuffer::unload();
+    BindSampler::unload();
+    BindTexture::unload();
+    BindTransformFeedback::unload();
+    BindVertexArray::unload();
+    BindVertexBuffer::unload();
+    BlendColor::unload();
+    BlendEquation::unload();
+    BlendEquationSeparate::unload();
+    BlendEquationSeparatei::unload();
+    BlendEquationi::unload();
+    BlendFunc::unload();
+    BlendFuncSeparate::unload();
+    BlendFuncSeparatei::unload();
+    BlendFunci::unload();
+    BlitFramebuffer::unload();
+    BufferData::unload();
+    BufferSubData::unload();
+    CheckFramebufferStatus::unload();
+    ClampColor::unload();
+    Clear::unload();
+    ClearBufferData::unload();
+    ClearBufferSubData::unload();
+    ClearBufferfi::unload();
+    ClearBufferfv::unload();
+    ClearBufferiv::unload();
+    ClearBufferuiv::unload();
+    ClearColor::unload();
+    ClearDepth::unload();
+    ClearDepthf::unload();
+    ClearStencil::unload();
+    ClientWaitSync::unload();
+    ColorMask::unload();
+    ColorMaski::unload();
+    ColorP3ui::unload();
+    ColorP3uiv::unload();
+    ColorP4ui::unload();
+    ColorP4uiv::unload();
+    CompileShader::unload();
+    CompressedTexImage1D::unload();
+    CompressedTexImage2D::unload();
+    CompressedTexImage3D::unload();
+    CompressedTexSubImage1D::unload();
+    CompressedTexSubImage2D::unload();
+    CompressedTexSubImage3D::unload();
+    CopyBufferSubData::unload();
+    CopyImageSubData::unload();
+    CopyTexImage1D::unload();
+    CopyTexImage2D::unload();
+    CopyTexSubImage1D::unload();
+    CopyTexSubImage2D::unload();
+    CopyTexSubImage3D::unload();
+    CreateProgram::unload();
+    CreateShader::unload();
+    CreateShaderProgramv::unload();
+    CullFace::unload();
+    DebugMessageCallback::unload();
+    DebugMessageControl::unload();
+    DebugMessageInsert::unload();
+    DeleteBuffers::unload();
+    DeleteFramebuffers::unload();
+    DeleteProgram::unload();
+    DeleteProgramPipelines::unload();
+    DeleteQueries::unload();
+    DeleteRenderbuffers::unload();
+    DeleteSamplers::unload();
+    DeleteShader::unload();
+    DeleteSync::unload();
+    DeleteTextures::unload();
+    DeleteTransformFeedbacks::unload();
+    DeleteVertexArrays::unload();
+    DepthFunc::unload();
+    DepthMask::unload();
+    DepthRange::unload();
+    DepthRangeArrayv::unload();
+    DepthRangeIndexed::unload();
+    DepthRangef::unload();
+    DetachShader::unload();
+    Disable::unload();
+    DisableVertexAttribArray::unload();
+    Disablei::unload();
+    DispatchCompute::unload();
+    DispatchComputeIndirect::unload();
+    DrawArrays::unload();
+    DrawArraysIndirect::unload();
+    DrawArraysInstanced::unload();
+    DrawArraysInstancedBaseInstance::unload();
+    DrawBuffer::unload();
+    DrawBuffers::unload();
+    DrawElements::unload();
+    DrawElementsBaseVertex::unload();
+    DrawElementsIndirect::unload();
+    DrawElementsInstanced::unload();
+    DrawElementsInstancedBaseInstance::unload();
+    DrawElementsInstancedBaseVertex::unload();
+    DrawElementsInstancedBaseVertexBaseInstance::unload();
+    DrawRangeElements::unload();
+    DrawRangeElementsBaseVertex::unload();
+    DrawTransformFeedback::unload();
+    DrawTransformFeedbackInstanced::unload();
+    DrawTransformFeedbackStream::unload();
+    DrawTransformFeedbackStreamInstanced::unload();
+    Enable::unload();
+    EnableVertexAttribArray::unload();
+    Enablei::unload();
+    EndConditionalRender::unload();
+    EndQuery::unload();
+    EndQueryIndexed::unload();
+    EndTransformFeedback::unload();
+    FenceSync::unload();
+    Finish::unload();
+    Flush::unload();
+    FlushMappedBufferRange::unload();
+    FramebufferParameteri::unload();
+    FramebufferRenderbuffer::unload();
+    FramebufferTexture::unload();
+    FramebufferTexture1D::unload();
+    FramebufferTexture2D::unload();
+    FramebufferTexture3D::unload();
+    FramebufferTextureLayer::unload();
+    FrontFace::unload();
+    GenBuffers::unload();
+    GenFramebuffers::unload();
+    GenProgramPipelines::unload();
+    GenQueries::unload();
+    GenRenderbuffers::unload();
+    GenSamplers::unload();
+    GenTextures::unload();
+    GenTransformFeedbacks::unload();
+    GenVertexArrays::unload();
+    GenerateMipmap::unload();
+    GetActiveAtomicCounterBufferiv::unload();
+    GetActiveAttrib::unload();
+    GetActiveSubroutineName::unload();
+    GetActiveSubroutineUniformName::unload();
+    GetActiveSubroutineUniformiv::unload();
+    GetActiveUniform::unload();
+    GetActiveUniformBlockName::unload();
+    GetActiveUniformBlockiv::unload();
+    GetActiveUniformName::unload();
+    GetActiveUniformsiv::unload();
+    GetAttachedShaders::unload();
+    GetAttribLocation::unload();
+    GetBooleani_v::unload();
+    GetBooleanv::unload();
+    GetBufferParameteri64v::unload();
+    GetBufferParameteriv::unload();
+    GetBufferPointerv::unload();
+    GetBufferSubData::unload();
+    GetCompressedTexImage::unload();
+    GetDebugMessageLog::unload();
+    GetDoublei_v::unload();
+    GetDoublev::unload();
+    GetError::unload();
+    GetFloati_v::unload();
+    GetFloatv::unload();
+    GetFragDataIndex::unload();
+    GetFragDataLocation::unload();
+    GetFramebufferAttachmentParameteriv::unload();
+    GetFramebufferParameteriv::unload();
+    GetInteger64i_v::unload();
+    GetInteger64v::unload();
+    GetIntegeri_v::unload();
+    GetIntegerv::unload();
+    GetInternalformati64v::unload();
+    GetInternalformativ::unload();
+    GetMultisamplefv::unload();
+    GetObjectLabel::unload();
+    GetObjectPtrLabel::unload();
+    GetProgramBinary::unload();
+    GetProgramInfoLog::unload();
+    GetProgramInterfaceiv::unload();
+    GetProgramPipelineInfoLog::unload();
+    GetProgramPipelineiv::unload();
+    GetProgramResourceIndex::unload();
+    GetProgramResourceLocation::unload();
+    GetProgramResourceLocationIndex::unload();
+    GetProgramResourceName::unload();
+    GetProgramResourceiv::unload();
+    GetProgramStageiv::unload();
+    GetProgramiv::unload();
+    GetQueryIndexediv::unload();
+    GetQueryObjecti64v::unload();
+    GetQueryObjectiv::unload();
+    GetQueryObjectui64v::unload();
+    GetQueryObjectuiv::unload();
+    GetQueryiv::unload();
+    GetRenderbufferParameteriv::unload();
+    GetSamplerParameterIiv::unload();
+    GetSamplerParameterIuiv::unload();
+    GetSamplerParameterfv::unload();
+    GetSamplerParameteriv::unload();
+    GetShaderInfoLog::unload();
+    GetShaderPrecisionFormat::unload();
+    GetShaderSource::unload();
+    GetShaderiv::unload();
+    GetString::unload();
+    GetStringi::unload();
+    GetSubroutineIndex::unload();
+    GetSubroutineUniformLocation::unload();
+    GetSynciv::unload();
+    GetTexImage::unload();
+    GetTexLevelParameterfv::unload();
+    GetTexLevelParameteriv::unload();
+    GetTexParameterIiv::unload();
+    GetTexParameterIuiv::unload();
+    GetTexParameterfv::unload();
+    GetTexParameteriv::unload();
+    GetTransformFeedbackVarying::unload();
+    GetUniformBlockIndex::unload();
+    GetUniformIndices::unload();
+    GetUniformLocation::unload();
+    GetUniformSubroutineuiv::unload();
+    GetUniformdv::unload();
+    GetUniformfv::unload();
+    GetUniformiv::unload();
+    GetUniformuiv::unload();
+    GetVertexAttribIiv::unload();
+    GetVertexAttribIuiv::unload();
+    GetVertexAttribLdv::unload();
+    GetVertexAttribPointerv::unload();
+    GetVertexAttribdv::unload();
+    GetVertexAttribfv::unload();
+    GetVertexAttribiv::unload();
+    Hint::unload();
+    InvalidateBufferData::unload();
+    InvalidateBufferSubData::unload();
+    InvalidateFramebuffer::unload();
+    InvalidateSubFramebuffer::unload();
+    InvalidateTexImage::unload();
+    InvalidateTexSubImage::unload();
+    IsBuffer::unload();
+    IsEnabled::unload();
+    IsEnabledi::unload();
+    IsFramebuffer::unload();
+    IsProgram::unload();
+    IsProgramPipeline::unload();
+    IsQuery::unload();
+    IsRenderbuffer::unload();
+    IsSampler::unload();
+    IsShader::unload();
+    IsSync::unload();
+    IsTexture::unload();
+    IsTransformFeedback::unload();
+    IsVertexArray::unload();
+    LineWidth::unload();
+    LinkProgram::unload();
+    LogicOp::unload();
+    MapBuffer::unload();
+    MapBufferRange::unload();
+    MemoryBarrier::unload();
+    MinSampleShading::unload();
+    MultiDrawArrays::unload();
+    MultiDrawArraysIndirect::unload();
+    MultiDrawElements::unload();
+    MultiDrawElementsBaseVertex::unload();
+    MultiDrawElementsIndirect::unload();
+    MultiTexCoordP1ui::unload();
+    MultiTexCoordP1uiv::unload();
+    MultiTexCoordP2ui::unload();
+    MultiTexCoordP2uiv::unload();
+    MultiTexCoordP3ui::unload();
+    MultiTexCoordP3uiv::unload();
+    MultiTexCoordP4ui::unload();
+    MultiTexCoordP4uiv::unload();
+    NormalP3ui::unload();
+    NormalP3uiv::unload();
+    ObjectLabel::unload();
+    ObjectPtrLabel::unload();
+    PatchParameterfv::unload();
+    PatchParameteri::unload();
+    PauseTransformFeedback::unload();
+    PixelStoref::unload();
+    PixelStorei::unload();
+    PointParameterf::unload();
+    PointParameterfv::unload();
+    PointParameteri::unload();
+    PointParameteriv::unload();
+    PointSize::unload();
+    PolygonMode::unload();
+    PolygonOffset::unload();
+    PopDebugGroup::unload();
+    PrimitiveRestartIndex::unload();
+    ProgramBinary::unload();
+    ProgramParameteri::unload();
+    ProgramUniform1d::unload();
+    ProgramUniform1dv::unload();
+    ProgramUniform1f::unload();
+    ProgramUniform1fv::unload();
+    ProgramUniform1i::unload();
+    ProgramUniform1iv::unload();
+    ProgramUniform1ui::unload();
+    ProgramUniform1uiv::unload();
+    ProgramUniform2d::unload();
+    ProgramUniform2dv::unload();
+    ProgramUniform2f::unload();
+    ProgramUniform2fv::unload();
+    ProgramUniform2i::unload();
+    ProgramUniform2iv::unload();
+    ProgramUniform2ui::unload();
+    ProgramUniform2uiv::unload();
+    ProgramUniform3d::unload();
+    ProgramUniform3dv::unload();
+    ProgramUniform3f::unload();
+    ProgramUniform3fv::unload();
+    ProgramUniform3i::unload();
+    ProgramUniform3iv::unload();
+    ProgramUniform3ui::unload();
+    ProgramUniform3uiv::unload();
+    ProgramUniform4d::unload();
+    ProgramUniform4dv::unload();
+    ProgramUniform4f::unload();
+    ProgramUniform4fv::unload();
+    ProgramUniform4i::unload();
+    ProgramUniform4iv::unload();
+    ProgramUniform4ui::unload();
+    ProgramUniform4uiv::unload();
+    ProgramUniformMatrix2dv::unload();
+    ProgramUniformMatrix2fv::unload();
+    ProgramUniformMatrix2x3dv::unload();
+    ProgramUniformMatrix2x3fv::unload();
+    ProgramUniformMatrix2x4dv::unload();
+    ProgramUniformMatrix2x4fv::unload();
+    ProgramUniformMatrix3dv::unload();
+    ProgramUniformMatrix3fv::unload();
+    ProgramUniformMatrix3x2dv::unload();
+    ProgramUniformMatrix3x2fv::unload();
+    ProgramUniformMatrix3x4dv::unload();
+    ProgramUniformMatrix3x4fv::unload();
+    ProgramUniformMatrix4dv::unload();
+    ProgramUniformMatrix4fv::unload();
+    ProgramUniformMatrix4x2dv::unload();
+    ProgramUniformMatrix4x2fv::unload();
+    ProgramUniformMatrix4x3dv::unload();
+    ProgramUniformMatrix4x3fv::unload();
+    ProvokingVertex::unload();
+    PushDebugGroup::unload();
+    QueryCounter::unload();
+    ReadBuffer::unload();
+    ReadPixels::unload();
+    ReleaseShaderCompiler::unload();
+    RenderbufferStorage::unload();
+    RenderbufferStorageMultisample::unload();
+    ResumeTransformFeedback::unload();
+    SampleCoverage::unload();
+    SampleMaski::unload();
+    SamplerParameterIiv::unload();
+    SamplerParameterIuiv::unload();
+    SamplerParameterf::unload();
+    SamplerParameterfv::unload();
+    SamplerParameteri::unload();
+    SamplerParameteriv::unload();
+    Scissor::unload();
+    ScissorArrayv::unload();
+    ScissorIndexed::unload();
+    ScissorIndexedv::unload();
+    SecondaryColorP3ui::unload();
+    SecondaryColorP3uiv::unload();
+    ShaderBinary::unload();
+    ShaderSource::unload();
+    ShaderStorageBlockBinding::unload();
+    StencilFunc::unload();
+    StencilFuncSeparate::unload();
+    StencilMask::unload();
+    StencilMaskSeparate::unload();
+    StencilOp::unload();
+    StencilOpSeparate::unload();
+    TexBuffer::unload();
+    TexBufferRange::unload();
+    TexCoordP1ui::unload();
+    TexCoordP1uiv::unload();
+    TexCoordP2ui::unload();
+    TexCoordP2uiv::unload();
+    TexCoordP3ui::unload();
+    TexCoordP3uiv::unload();
+    TexCoordP4ui::unload();
+    TexCoordP4uiv::unload();
+    TexImage1D::unload();
+    TexImage2D::unload();
+    TexImage2DMultisample::unload();
+    TexImage3D::unload();
+    TexImage3DMultisample::unload();
+    TexParameterIiv::unload();
+    TexParameterIuiv::unload();
+    TexParameterf::unload();
+    TexParameterfv::unload();
+    TexParameteri::unload();
+    TexParameteriv::unload();
+    TexStorage1D::unload();
+    TexStorage2D::unload();
+    TexStorage2DMultisample::unload();
+    TexStorage3D::unload();
+    TexStorage3DMultisample::unload();
+    TexSubImage1D::unload();
+    TexSubImage2D::unload();
+    TexSubImage3D::unload();
+    TextureView::unload();
+    TransformFeedbackVaryings::unload();
+    Uniform1d::unload();
+    Uniform1dv::unload();
+    Uniform1f::unload();
+    Uniform1fv::unload();
+    Uniform1i::unload();
+    Uniform1iv::unload();
+    Uniform1ui::unload();
+    Uniform1uiv::unload();
+    Uniform2d::unload();
+    Uniform2dv::unload();
+    Uniform2f::unload();
+    Uniform2fv::unload();
+    Uniform2i::unload();
+    Uniform2iv::unload();
+    Uniform2ui::unload();
+    Uniform2uiv::unload();
+    Uniform3d::unload();
+    Uniform3dv::unload();
+    Uniform3f::unload();
+    Uniform3fv::unload();
+    Uniform3i::unload();
+    Uniform3iv::unload();
+    Uniform3ui::unload();
+    Uniform3uiv::unload();
+    Uniform4d::unload();
+    Uniform4dv::unload();
+    Uniform4f::unload();
+    Uniform4fv::unload();
+    Uniform4i::unload();
+    Uniform4iv::unload();
+    Uniform4ui::unload();
+    Uniform4uiv::unload();
+    UniformBlockBinding::unload();
+    UniformMatrix2dv::unload();
+    UniformMatrix2fv::unload();
+    UniformMatrix2x3dv::unload();
+    UniformMatrix2x3fv::unload();
+    UniformMatrix2x4dv::unload();
+    UniformMatrix2x4fv::unload();
+    UniformMatrix3dv::unload();
+    UniformMatrix3fv::unload();
+    UniformMatrix3x2dv::unload();
+    UniformMatrix3x2fv::unload();
+    UniformMatrix3x4dv::unload();
+    UniformMatrix3x4fv::unload();
+    UniformMatrix4dv::unload();
+    UniformMatrix4fv::unload();
+    UniformMatrix4x2dv::unload();
+    UniformMatrix4x2fv::unload();
+    UniformMatrix4x3dv::unload();
+    UniformMatrix4x3fv::unload();
+    UniformSubroutinesuiv::unload();
+    UnmapBuffer::unload();
+    UseProgram::unload();
+    UseProgramStages::unload();
+    ValidateProgram::unload();
+    ValidateProgramPipeline::unload();
+    VertexAttrib1d::unload();
+    VertexAttrib1dv::unload();
+    VertexAttrib1f::unload();
+    VertexAttrib1fv::unload();
+    VertexAttrib1s::unload();
+    VertexAttrib1sv::unload();
+    VertexAttrib2d::unload();
+    VertexAttrib2dv::unload();
+    VertexAttrib2f::unload();
+    VertexAttrib2fv::unload();
+    VertexAttrib2s::unload();
+    VertexAttrib2sv::unload();
+    VertexAttrib3d::unload();
+    VertexAttrib3dv::unload();
+    VertexAttrib3f::unload();
+    VertexAttrib3fv::unload();
+    VertexAttrib3s::unload();
+    VertexAttrib3sv::unload();
+    VertexAttrib4Nbv::unload();
+    VertexAttrib4Niv::unload();
+    VertexAttrib4Nsv::unload();
+    VertexAttrib4Nub::unload();
+    VertexAttrib4Nubv::unload();
+    VertexAttrib4Nuiv::unload();
+    VertexAttrib4Nusv::unload();
+    VertexAttrib4bv::unload();
+    VertexAttrib4d::unload();
+    VertexAttrib4dv::unload();
+    VertexAttrib4f::unload();
+    VertexAttrib4fv::unload();
+    VertexAttrib4iv::unload();
+    VertexAttrib4s::unload();
+    VertexAttrib4sv::unload();
+    VertexAttrib4ubv::unload();
+    VertexAttrib4uiv::unload();
+    VertexAttrib4usv::unload();
+    VertexAttribBinding::unload();
+    VertexAttribDivisor::unload();
+    VertexAttribFormat::unload();
+    VertexAttribI1i::unload();
+    VertexAttribI1iv::unload();
+    VertexAttribI1ui::unload();
+    VertexAttribI1uiv::unload();
+    VertexAttribI2i::unload();
+    VertexAttribI2iv::unload();
+    VertexAttribI2ui::unload();
+    VertexAttribI2uiv::unload();
+    VertexAttribI3i::unload();
+    VertexAttribI3iv::unload();
+    VertexAttribI3ui::unload();
+    VertexAttribI3uiv::unload();
+    VertexAttribI4bv::unload();
+    VertexAttribI4i::unload();
+    VertexAttribI4iv::unload();
+    VertexAttribI4sv::unload();
+    VertexAttribI4ubv::unload();
+    VertexAttribI4ui::unload();
+    VertexAttribI4uiv::unload();
+    VertexAttribI4usv::unload();
+    VertexAttribIFormat::unload();
+    VertexAttribIPointer::unload();
+    VertexAttribL1d::unload();
+    VertexAttribL1dv::unload();
+    VertexAttribL2d::unload();
+    VertexAttribL2dv::unload();
+    VertexAttribL3d::unload();
+    VertexAttribL3dv::unload();
+    VertexAttribL4d::unload();
+    VertexAttribL4dv::unload();
+    VertexAttribLFormat::unload();
+    VertexAttribLPointer::unload();
+    VertexAttribP1ui::unload();
+    VertexAttribP1uiv::unload();
+    VertexAttribP2ui::unload();
+    VertexAttribP2uiv::unload();
+    VertexAttribP3ui::unload();
+    VertexAttribP3uiv::unload();
+    VertexAttribP4ui::unload();
+    VertexAttribP4uiv::unload();
+    VertexAttribPointer::unload();
+    VertexBindingDivisor::unload();
+    VertexP2ui::unload();
+    VertexP2uiv::unload();
+    VertexP3ui::unload();
+    VertexP3uiv::unload();
+    VertexP4ui::unload();
+    VertexP4uiv::unload();
+    Viewport::unload();
+    ViewportArrayv::unload();
+    ViewportIndexedf::unload();
+    ViewportIndexedfv::unload();
+    WaitSync::unload();
 }
 